@@ -0,0 +1,44 @@
+//! Derive macros for `bevy_mod_progress`.
+//! These are re-exported by `bevy_mod_progress` with the `derive` feature, and shouldn't be used directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Adds convenience constructors for using a type as a progress marker.
+///
+/// See the documentation in `bevy_mod_progress` for details.
+#[proc_macro_derive(ProgressMarker)]
+pub fn derive_progress_marker(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            /// Returns a plugin that tracks progress for this type as a resource.
+            pub fn plugin() -> ::bevy_mod_progress::ResourceProgressTrackingPlugin<Self> {
+                ::core::default::Default::default()
+            }
+
+            /// Returns a plugin that tracks progress for this type on entities.
+            pub fn entity_plugin() -> ::bevy_mod_progress::EntityProgressTrackingPlugin<Self> {
+                ::core::default::Default::default()
+            }
+
+            /// Returns a run condition that is `true` if progress for this type was complete when last checked.
+            pub fn done_condition() -> impl FnMut(
+                ::core::option::Option<::bevy_mod_progress::__macro_exports::Res<::bevy_mod_progress::Progress<Self>>>,
+            ) -> bool + ::core::clone::Clone {
+                ::bevy_mod_progress::progress_done::<Self>()
+            }
+
+            /// Returns a run condition that is `true` if progress for this type was incomplete when last checked.
+            pub fn not_done_condition() -> impl FnMut(
+                ::core::option::Option<::bevy_mod_progress::__macro_exports::Res<::bevy_mod_progress::Progress<Self>>>,
+            ) -> bool + ::core::clone::Clone {
+                ::bevy_mod_progress::progress_not_done::<Self>()
+            }
+        }
+    }.into()
+}
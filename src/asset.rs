@@ -0,0 +1,162 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_asset::{prelude::*, LoadState, LoadedFolder, RecursiveDependencyLoadState, UntypedHandle};
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Records the load state of assets in an [`AssetTracker<T>`] into the [`Progress<T>`] resource.
+pub struct AssetProgressPlugin<T: ?Sized> {
+    /// The schedule in which asset load states are recorded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for AssetProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for AssetProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AssetTracker<T>>();
+
+        app.add_systems(self.schedule, asset_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn asset_progress_system<T: ?Sized + Send + Sync + 'static>(
+    server: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    tracker: Res<AssetTracker<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        for entry in &tracker.entries {
+            match entry {
+                Entry::Asset(handle) => record_asset(&server, progress, handle),
+                Entry::Recursive(handle) => record_recursive(&server, progress, handle),
+                Entry::Folder(handle) => match folders.get(handle) {
+                    // Each asset in the folder is counted once the folder has loaded
+                    Some(folder) => for handle in &folder.handles {
+                        record_recursive(&server, progress, handle);
+                    },
+
+                    // Until then, the folder itself is one unit of work
+                    None => record_asset(&server, progress, &handle.clone().untyped()),
+                },
+            }
+        }
+    });
+}
+
+fn record_asset<T: ?Sized>(
+    server: &AssetServer,
+    progress: &mut Progress<T>,
+    handle: &UntypedHandle,
+) {
+    match server.load_state(handle) {
+        LoadState::Loaded => progress.track(1, 1),
+        LoadState::Failed(error) => progress.fail_with(error.to_string()),
+        LoadState::NotLoaded | LoadState::Loading => progress.track(0, 1),
+    }
+}
+
+fn record_recursive<T: ?Sized>(
+    server: &AssetServer,
+    progress: &mut Progress<T>,
+    handle: &UntypedHandle,
+) {
+    // Failures of the asset itself are reported with a more specific reason
+    if server.load_state(handle) != LoadState::Loaded {
+        return record_asset(server, progress, handle);
+    }
+
+    match server.recursive_dependency_load_state(handle) {
+        RecursiveDependencyLoadState::Loaded => progress.track(1, 1),
+        RecursiveDependencyLoadState::Failed => progress.fail_with(format!("dependency of {:?} failed to load", handle.path())),
+        RecursiveDependencyLoadState::NotLoaded | RecursiveDependencyLoadState::Loading => progress.track(0, 1),
+    }
+}
+
+enum Entry {
+    Asset(UntypedHandle),
+    Recursive(UntypedHandle),
+    Folder(Handle<LoadedFolder>),
+}
+
+/// A set of asset handles whose load states contribute to the [`Progress<T>`] resource.
+///
+/// Each asset is one unit of work, which is done once the asset has loaded.
+/// Assets that fail to load are recorded as failed work.
+///
+/// Folders loaded with [`AssetServer::load_folder`] can also be tracked with [`add_folder`](Self::add_folder).
+/// Each discovered asset in the folder is then counted as its own unit of work.
+///
+/// Added by [`AssetProgressPlugin<T>`].
+#[derive(Resource)]
+pub struct AssetTracker<T: ?Sized> {
+    entries: Vec<Entry>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> AssetTracker<T> {
+    /// Adds an asset to be tracked.
+    pub fn add(&mut self, handle: impl Into<UntypedHandle>) {
+        self.entries.push(Entry::Asset(handle.into()));
+    }
+
+    /// Adds an asset to be tracked, which is only done once all of its dependencies have recursively loaded.
+    pub fn add_recursive(&mut self, handle: impl Into<UntypedHandle>) {
+        self.entries.push(Entry::Recursive(handle.into()));
+    }
+
+    /// Adds a folder to be tracked.
+    ///
+    /// Until the folder has loaded, it counts as one unit of work.
+    /// Afterwards, each asset in the folder is tracked as if added with [`add_recursive`](Self::add_recursive).
+    pub fn add_folder(&mut self, handle: Handle<LoadedFolder>) {
+        self.entries.push(Entry::Folder(handle));
+    }
+
+    /// Stops tracking all assets.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of handles added to the tracker.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no handles have been added to the tracker.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<T: ?Sized> Default for AssetTracker<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            _p1: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::borrow::Cow;
+use crate::Progress;
+
+impl<T: ?Sized> Progress<T> {
+    /// Enables attribution, keeping a tally of work for each source recorded with [`track_from`](Self::track_from).
+    ///
+    /// This is useful to find out which system owes the remaining work when a tracker doesn't finish.
+    pub fn with_attribution(mut self) -> Self {
+        self.attribution.enabled = true;
+        self
+    }
+
+    /// Records progress like [`track`](Self::track), attributing it to `source`, such as the name of a system.
+    ///
+    /// If attribution is enabled with [`with_attribution`](Self::with_attribution),
+    /// the work is also added to the tally for `source`, which can be read with [`sources`](Self::sources).
+    pub fn track_from(&mut self, source: impl Into<Cow<'static, str>>, done: u32, total: u32) {
+        self.record(done as u64, total as u64, 1.0);
+        if !self.attribution.enabled { return }
+
+        let source = source.into();
+        let tally = match self.attribution.sources.iter_mut().find(|tally| tally.source == source) {
+            Some(v) => v,
+            None => {
+                self.attribution.sources.push(SourceTally { source, done: 0, total: 0 });
+                self.attribution.sources.last_mut().unwrap()
+            },
+        };
+
+        tally.done = tally.done.saturating_add(done as u64);
+        tally.total = tally.total.saturating_add(total as u64);
+    }
+
+    /// Returns the work recorded by each source, as completed and total work, in the order sources first recorded work.
+    ///
+    /// Sources stay listed after the tracker is reset, with no work, until it is cleared.
+    /// This is always empty if attribution is not enabled.
+    pub fn sources(&self) -> impl Iterator<Item = (&str, (u64, u64))> {
+        self.attribution.sources.iter()
+            .map(|tally| (tally.source.as_ref(), (tally.done, tally.total)))
+    }
+
+    /// Returns the sources that recorded work that has not been completed yet.
+    pub fn unfinished_sources(&self) -> impl Iterator<Item = (&str, (u64, u64))> {
+        self.sources().filter(|(_, (done, total))| done < total)
+    }
+}
+
+#[derive(Clone)]
+struct SourceTally {
+    source: Cow<'static, str>,
+    done: u64,
+    total: u64,
+}
+
+#[derive(Clone)]
+pub(crate) struct AttributionState {
+    enabled: bool,
+    sources: Vec<SourceTally>,
+}
+
+impl AttributionState {
+    pub(crate) fn new() -> Self {
+        Self {
+            enabled: false,
+            sources: Vec::new(),
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        for tally in &mut self.sources {
+            tally.done = 0;
+            tally.total = 0;
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.sources.clear();
+    }
+}
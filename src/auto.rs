@@ -0,0 +1,64 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use crate::{Cancelled, Phase, Progress};
+
+/// Attaches a fresh [`Progress<T>`] to every entity that `C` is added to.
+///
+/// Entities that already have a [`Progress<T>`] when `C` is added keep their own tracker.
+/// The new tracker is empty until work is recorded in it, so [`DespawnOnDone<T>`](crate::DespawnOnDone)
+/// and [`CompletedMarker`](crate::CompletedMarker) leave the entity alone until that work completes.
+/// If the tracker is removed from an entity with `C` while it is still running,
+/// such as by despawning the entity, [`Cancelled<T>`] is raised for it.
+///
+/// This prevents jobs from being spawned without a tracker by mistake.
+/// Requires [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin).
+pub struct AutoProgressPlugin<C, T: ?Sized> {
+    _p1: PhantomData<C>,
+    _p2: PhantomData<T>,
+}
+
+impl<C, T: ?Sized> Default for AutoProgressPlugin<C, T> {
+    fn default() -> Self {
+        Self {
+            _p1: PhantomData,
+            _p2: PhantomData,
+        }
+    }
+}
+
+impl<C: Component, T: Send + Sync + 'static> Plugin for AutoProgressPlugin<C, T> {
+    fn build(&self, app: &mut App) {
+        app.observe(auto_insert_observer::<C, T>);
+        app.observe(auto_cancel_observer::<C, T>);
+    }
+}
+
+fn auto_insert_observer<C: Component, T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<OnAdd, C>,
+    mut commands: Commands,
+    trackers: Query<(), With<Progress<T>>>,
+) {
+    let entity = trigger.entity();
+    if trackers.contains(entity) { return }
+
+    if let Some(mut entity) = commands.get_entity(entity) {
+        entity.try_insert(Progress::<T>::new());
+    }
+}
+
+fn auto_cancel_observer<C: Component, T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<OnRemove, Progress<T>>,
+    mut commands: Commands,
+    query: Query<&Progress<T>, With<C>>,
+) {
+    let entity = trigger.entity();
+
+    let tracker = match query.get(entity) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    if tracker.phase != Phase::Running { return }
+    commands.trigger_targets(Cancelled::<T>::new(tracker, Some(entity)), [entity]);
+}
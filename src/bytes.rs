@@ -0,0 +1,173 @@
+use std::{io, pin::Pin, task::{Context, Poll}};
+use bevy_tasks::futures_lite::{AsyncRead, Stream};
+use bevy_utils::ConditionalSendFuture;
+use crate::SharedProgress;
+
+impl<T: ?Sized> SharedProgress<T> {
+    /// Records bytes, or other work too large for [`track`](Self::track).
+    pub fn track_bytes(&self, done: u64, total: u64) {
+        self.add(done, total);
+    }
+
+    /// Wraps `stream`, recording the size of each item as it's received.
+    ///
+    /// `measure` returns the number of bytes in each item, such as the length of a chunk from a fetch body.
+    /// If the total size is known, such as from a `Content-Length` header, it's recorded up front.
+    /// Otherwise, each item is recorded as both done and total work as it arrives.
+    ///
+    /// Either way, one extra unit of total work is recorded up front, and only completed
+    /// once the stream ends, so the tracker can't complete while more items may arrive.
+    /// If the wrapper is dropped before the stream ends, the tracker never completes.
+    ///
+    /// The stream doesn't need to be [`Send`], so it works with browser APIs on wasm.
+    /// Streams that aren't [`Unpin`] can be pinned with [`Box::pin`].
+    pub fn track_stream<S, F>(&self, stream: S, total: Option<u64>, measure: F) -> TrackedStream<T, S, F>
+    where
+        S: Stream + Unpin,
+        F: FnMut(&S::Item) -> u64,
+    {
+        self.track_bytes(0, total.unwrap_or(0) + 1);
+
+        TrackedStream {
+            inner: stream,
+            measure,
+            known_total: total.is_some(),
+            finished: false,
+            progress: self.clone(),
+        }
+    }
+
+    /// Wraps `reader`, recording the number of bytes read.
+    ///
+    /// If the total size is known, it's recorded up front, as in [`track_stream`](Self::track_stream).
+    /// The tracker can't complete until the reader reaches the end of its data.
+    pub fn track_reader<R: AsyncRead + Unpin>(&self, reader: R, total: Option<u64>) -> TrackedReader<T, R> {
+        self.track_bytes(0, total.unwrap_or(0) + 1);
+
+        TrackedReader {
+            inner: reader,
+            known_total: total.is_some(),
+            finished: false,
+            progress: self.clone(),
+        }
+    }
+
+    /// Spawns the future returned by `f`, passing it a clone of this handle.
+    ///
+    /// The task is detached, so it runs to completion on its own.
+    /// On wasm, it's spawned on the browser's single-threaded executor with
+    /// `wasm_bindgen_futures::spawn_local`, so the future doesn't need to be [`Send`],
+    /// and runs between frames, so it shouldn't block.
+    /// Elsewhere, it's spawned on the [`IoTaskPool`](bevy_tasks::IoTaskPool),
+    /// panicking if it hasn't been initialised, such as by `TaskPoolPlugin`.
+    pub fn spawn<F, Fut>(&self, f: F)
+    where
+        F: FnOnce(SharedProgress<T>) -> Fut,
+        Fut: ConditionalSendFuture<Output = ()> + 'static,
+    {
+        #[cfg(target_arch="wasm32")]
+        wasm_bindgen_futures::spawn_local(f(self.clone()));
+
+        #[cfg(not(target_arch="wasm32"))]
+        bevy_tasks::IoTaskPool::get().spawn(f(self.clone())).detach();
+    }
+
+    fn record_chunk(&self, len: u64, known_total: bool) {
+        match known_total {
+            true => self.track_bytes(len, 0),
+            false => self.track_bytes(len, len),
+        }
+    }
+
+    /// Completes the extra unit of work recorded when wrapping, once the data has ended.
+    fn record_end(&self, finished: &mut bool) {
+        if *finished { return }
+        *finished = true;
+        self.track_bytes(1, 0);
+    }
+}
+
+/// A [`Stream`] that records the size of its items into a [`SharedProgress<T>`].
+///
+/// Created with [`SharedProgress::track_stream`].
+pub struct TrackedStream<T: ?Sized, S, F> {
+    inner: S,
+    measure: F,
+    known_total: bool,
+    finished: bool,
+    progress: SharedProgress<T>,
+}
+
+impl<T: ?Sized, S, F> TrackedStream<T, S, F> {
+    /// Returns the wrapped stream.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<T: ?Sized, S, F> Stream for TrackedStream<T, S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&S::Item) -> u64,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(Some(v)) => v,
+            Poll::Ready(None) => {
+                this.progress.record_end(&mut this.finished);
+                return Poll::Ready(None);
+            },
+            Poll::Pending => return Poll::Pending,
+        };
+
+        this.progress.record_chunk((this.measure)(&item), this.known_total);
+        Poll::Ready(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+// The fields are never pinned, so moving them is fine.
+impl<T: ?Sized, S: Unpin, F> Unpin for TrackedStream<T, S, F> {}
+
+/// An [`AsyncRead`] that records the number of bytes read into a [`SharedProgress<T>`].
+///
+/// Created with [`SharedProgress::track_reader`].
+pub struct TrackedReader<T: ?Sized, R> {
+    inner: R,
+    known_total: bool,
+    finished: bool,
+    progress: SharedProgress<T>,
+}
+
+impl<T: ?Sized, R> TrackedReader<T, R> {
+    /// Returns the wrapped reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<T: ?Sized, R: AsyncRead + Unpin> AsyncRead for TrackedReader<T, R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.inner).poll_read(cx, buf);
+
+        match result {
+            // Reading nothing into a non-empty buffer means the reader has ended
+            Poll::Ready(Ok(0)) if !buf.is_empty() => this.progress.record_end(&mut this.finished),
+            Poll::Ready(Ok(len)) => this.progress.record_chunk(len as u64, this.known_total),
+            _ => {},
+        }
+
+        result
+    }
+}
+
+impl<T: ?Sized, R: Unpin> Unpin for TrackedReader<T, R> {}
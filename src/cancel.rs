@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use crate::{Progress, ProgressTask, storage::TrackerComponent};
+
+/// An event that can be triggered to abort a progress tracker.
+///
+/// Trigger this without a target to cancel the [`Progress<T>`] resource,
+/// or targeted at entities to cancel their [`Progress<T>`] components.
+/// The tracker is cleared, and [`Cancelled<T>`] is raised for it.
+///
+/// This is handled by the tracking plugins, requiring
+/// [`ResourceProgressTrackingPlugin<T>`](crate::ResourceProgressTrackingPlugin) or
+/// [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin) respectively.
+#[derive(Event)]
+pub struct CancelProgress<T: ?Sized> {
+    despawn_tasks: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> CancelProgress<T> {
+    /// Creates a new [`CancelProgress`] event.
+    pub fn new() -> Self {
+        Self {
+            despawn_tasks: false,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Also despawns all entities with a [`ProgressTask<T>`] when cancelling the resource.
+    pub fn despawning_tasks(mut self) -> Self {
+        self.despawn_tasks = true;
+        self
+    }
+}
+
+impl<T: ?Sized> Default for CancelProgress<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn resource_cancel_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<CancelProgress<T>>,
+    mut commands: Commands,
+    resource: Option<ResMut<Progress<T>>>,
+    tasks: Query<Entity, With<ProgressTask<T>>>,
+) {
+    // Targeted cancellations are for entity trackers
+    if trigger.entity() != Entity::PLACEHOLDER { return }
+
+    if trigger.event().despawn_tasks {
+        for entity in &tasks {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let event = Cancelled::<T>::new(&resource, None);
+    resource.clear();
+    commands.trigger(event);
+}
+
+pub(crate) fn entity_cancel_observer<T: ?Sized + Send + Sync + 'static, C: TrackerComponent<T>>(
+    trigger: Trigger<CancelProgress<T>>,
+    mut commands: Commands,
+    mut query: Query<&mut C>,
+) {
+    let entity = trigger.entity();
+    if entity == Entity::PLACEHOLDER { return }
+
+    let mut tracker = match query.get_mut(entity) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    let tracker = tracker.tracker_mut();
+    let event = Cancelled::<T>::new(tracker, Some(entity));
+    tracker.clear();
+    commands.trigger_targets(event, [entity]);
+}
+
+/// An observer event raised when a progress tracker is cancelled with [`CancelProgress<T>`].
+#[derive(Event)]
+pub struct Cancelled<T: ?Sized> {
+    done: u64,
+    total: u64,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Cancelled<T> {
+    pub(crate) fn new(progress: &Progress<T>, entity: Option<Entity>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            done,
+            total,
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that had been completed and the total units of work when the tracker was cancelled.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the entity whose tracker was cancelled, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
@@ -0,0 +1,137 @@
+use std::{marker::PhantomData, sync::{mpsc::{channel, Receiver, Sender}, Mutex, PoisonError}};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Records work sent through [`ProgressSender<T>`] handles into the [`Progress<T>`] resource.
+pub struct ProgressChannelPlugin<T: ?Sized> {
+    /// The schedule in which received work is recorded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressChannelPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressChannelPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProgressReceiver<T>>();
+
+        app.add_systems(self.schedule, channel_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn channel_progress_system<T: ?Sized + Send + Sync + 'static>(
+    mut receiver: ResMut<ProgressReceiver<T>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    resource: Option<ResMut<Progress<T>>>,
+) {
+    receiver.drain();
+
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let receiver = receiver.as_mut();
+    let (done, total) = receiver.work;
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut receiver.synced, |progress| {
+        progress.record(done, total, 1.0);
+    });
+}
+
+/// A handle for sending work to the [`ProgressReceiver<T>`] resource over a channel.
+///
+/// Obtained with [`ProgressReceiver::sender`].
+pub struct ProgressSender<T: ?Sized> {
+    sender: Sender<(u64, u64)>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressSender<T> {
+    /// Sends progress, including its total work and done work.
+    ///
+    /// Returns `false` if the receiver no longer exists.
+    pub fn track(&self, done: u32, total: u32) -> bool {
+        self.sender.send((done as u64, total as u64)).is_ok()
+    }
+}
+
+impl<T: ?Sized> Clone for ProgressSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+/// Receives work sent by [`ProgressSender<T>`] handles.
+///
+/// Received work is cumulative, and is recorded into the [`Progress<T>`] resource
+/// every tick by [`ProgressChannelPlugin<T>`], in the order it was sent.
+/// If the resource isn't reset every tick, only the work received since the last tick is recorded.
+#[derive(Resource)]
+pub struct ProgressReceiver<T: ?Sized> {
+    sender: Sender<(u64, u64)>,
+    receiver: Mutex<Receiver<(u64, u64)>>,
+    work: (u64, u64),
+    synced: SyncedWork,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressReceiver<T> {
+    /// Returns a new [`ProgressSender`] for this receiver.
+    pub fn sender(&self) -> ProgressSender<T> {
+        ProgressSender {
+            sender: self.sender.clone(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that has been received, as of the last time it was recorded.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        self.work
+    }
+
+    /// Clears all received work, including work that has been sent but not yet received.
+    pub fn clear(&mut self) {
+        self.drain();
+        self.work = (0, 0);
+        self.synced = SyncedWork::default();
+    }
+
+    fn drain(&mut self) {
+        let receiver = self.receiver.get_mut().unwrap_or_else(PoisonError::into_inner);
+        for (done, total) in receiver.try_iter() {
+            self.work.0 = self.work.0.saturating_add(done);
+            self.work.1 = self.work.1.saturating_add(total);
+        }
+    }
+}
+
+impl<T: ?Sized> Default for ProgressReceiver<T> {
+    fn default() -> Self {
+        let (sender, receiver) = channel();
+
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+            work: (0, 0),
+            synced: SyncedWork::default(),
+            _p1: PhantomData,
+        }
+    }
+}
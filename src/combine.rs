@@ -0,0 +1,103 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Phase, Progress, ProgressPaused, ProgressSystems};
+
+type AddSource = Box<dyn Fn(&mut App, InternedScheduleLabel) + Send + Sync>;
+
+/// Records the progress of several source trackers into the [`Progress<C>`] resource, as a weighted sum.
+///
+/// Each source contributes its fraction of work done, scaled by its weight, as fractional work
+/// (see [`Progress::track_f64`]). The combined tracker only completes once every source has completed,
+/// so [`Done<C>`](crate::Done) is raised once all sources are done.
+///
+/// Sources are read as of their last check, so the combined tracker lags a tick behind them.
+/// Tracking for `C` must still be added, such as with [`ResourceProgressTrackingPlugin<C>`](crate::ResourceProgressTrackingPlugin).
+pub struct CombinedProgressPlugin<C: ?Sized> {
+    /// The schedule in which sources are combined.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    sources: Vec<AddSource>,
+    _p1: PhantomData<C>,
+}
+
+impl<C: ?Sized> Default for CombinedProgressPlugin<C> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            sources: Vec::new(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<C: Send + Sync + 'static> CombinedProgressPlugin<C> {
+    /// Adds the [`Progress<S>`] resource as a source, contributing `weight` to the combined tracker.
+    pub fn with_source<S: Send + Sync + 'static>(mut self, weight: f32) -> Self {
+        self.sources.push(Box::new(move |app, schedule| {
+            app.insert_resource(CombinedSource::<C, S> {
+                weight: weight as f64,
+                completed: false,
+                _p1: PhantomData,
+                _p2: PhantomData,
+            });
+
+            app.add_systems(schedule, combine_system::<C, S>
+                .before(ProgressSystems::Check)
+                .run_if(not(resource_exists::<ProgressPaused<C>>))
+                .run_if(tracking_enabled::<C>));
+        }));
+
+        self
+    }
+}
+
+impl<C: Send + Sync + 'static> Plugin for CombinedProgressPlugin<C> {
+    fn build(&self, app: &mut App) {
+        for source in &self.sources {
+            source(app, self.schedule);
+        }
+    }
+}
+
+#[derive(Resource)]
+struct CombinedSource<C: ?Sized, S: ?Sized> {
+    weight: f64,
+    // Whether the source has completed, and hasn't received new work since
+    completed: bool,
+    _p1: PhantomData<C>,
+    _p2: PhantomData<S>,
+}
+
+fn combine_system<C: ?Sized + Send + Sync + 'static, S: ?Sized + Send + Sync + 'static>(
+    mut state: ResMut<CombinedSource<C, S>>,
+    combined: Option<ResMut<Progress<C>>>,
+    source: Option<Res<Progress<S>>>,
+    policies: Option<Res<ResetPolicies<C>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut combined = match combined {
+        Some(v) => v,
+        None => return,
+    };
+
+    let fract = match source {
+        Some(source) if source.previous.1 > 0 || source.previous_float.1 > 0.0 => {
+            state.completed = source.phase == Phase::Complete;
+            source.previous_fract as f64
+        },
+
+        // Empty sources are done if they completed before becoming empty
+        _ => 0.0,
+    };
+
+    let done = match state.completed {
+        true => state.weight,
+        false => state.weight * fract,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut combined, &mut synced, |progress| {
+        progress.track_f64(done, state.weight);
+    });
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+use bevy_ecs::{prelude::*, system::EntityCommands, world::Command};
+use crate::{Progress, ResetProgress};
+
+/// Extension trait for working with progress trackers through [`Commands`].
+pub trait ProgressCommandsExt {
+    /// Records progress into the [`Progress<T>`] resource when the command is applied.
+    ///
+    /// Does nothing if the resource doesn't exist at that point.
+    fn track_progress<T: ?Sized + Send + Sync + 'static>(&mut self, done: u32, total: u32) -> &mut Self;
+
+    /// Resets the [`Progress<T>`] resource, as if by triggering [`ResetProgress<T>`].
+    fn reset_progress<T: ?Sized + Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+
+impl ProgressCommandsExt for Commands<'_, '_> {
+    fn track_progress<T: ?Sized + Send + Sync + 'static>(&mut self, done: u32, total: u32) -> &mut Self {
+        self.add(TrackProgress::<T>::new(done, total));
+        self
+    }
+
+    fn reset_progress<T: ?Sized + Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.trigger(ResetProgress::<T>::new());
+        self
+    }
+}
+
+/// Extension trait for working with progress trackers through [`EntityCommands`].
+pub trait ProgressEntityCommandsExt {
+    /// Records progress into the entity's [`Progress<T>`] component when the command is applied.
+    ///
+    /// Does nothing if the entity doesn't have the component at that point.
+    fn track_progress<T: ?Sized + Send + Sync + 'static>(&mut self, done: u32, total: u32) -> &mut Self;
+
+    /// Resets the entity's [`Progress<T>`] component, as if by triggering [`ResetProgress<T>`] targeting it.
+    fn reset_progress<T: ?Sized + Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+
+impl ProgressEntityCommandsExt for EntityCommands<'_> {
+    fn track_progress<T: ?Sized + Send + Sync + 'static>(&mut self, done: u32, total: u32) -> &mut Self {
+        self.add(move |entity: Entity, world: &mut World| {
+            if let Some(mut tracker) = world.get_mut::<Progress<T>>(entity) {
+                tracker.track(done, total);
+            }
+        })
+    }
+
+    fn reset_progress<T: ?Sized + Send + Sync + 'static>(&mut self) -> &mut Self {
+        let entity = self.id();
+        self.commands().trigger_targets(ResetProgress::<T>::new(), [entity]);
+        self
+    }
+}
+
+/// A [`Command`] that records progress into the [`Progress<T>`] resource.
+///
+/// Does nothing if the resource doesn't exist.
+pub struct TrackProgress<T: ?Sized> {
+    done: u32,
+    total: u32,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> TrackProgress<T> {
+    /// Creates a new [`TrackProgress`] command.
+    pub fn new(done: u32, total: u32) -> Self {
+        Self {
+            done,
+            total,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> Command for TrackProgress<T> {
+    fn apply(self, world: &mut World) {
+        if let Some(mut resource) = world.get_resource_mut::<Progress<T>>() {
+            resource.track(self.done, self.total);
+        }
+    }
+}
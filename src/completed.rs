@@ -0,0 +1,89 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use crate::{Done, Progress, Started};
+
+/// Whether entities are marked with [`Completed<T>`] when their tracker finishes.
+///
+/// Set on [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin)
+/// or [`ProgressTrackingPlugin<T>`](crate::ProgressTrackingPlugin).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CompletedMarker {
+    /// Entities are not marked.
+    #[default]
+    Disabled,
+
+    /// [`Completed<T>`] is inserted when the tracker finishes,
+    /// and removed again if the tracker restarts.
+    Insert,
+
+    /// [`Completed<T>`] is inserted when the tracker finishes,
+    /// and the [`Progress<T>`] component is removed.
+    /// Trackers that are done because they're empty aren't removed.
+    Replace,
+}
+
+/// A marker component inserted on entities whose [`Progress<T>`] tracker has finished.
+///
+/// This allows finished entities to be found with query filters like `With<Completed<T>>`,
+/// instead of observing [`Done<T>`]. Inserted depending on the [`CompletedMarker`] setting.
+#[derive(Component)]
+pub struct Completed<T: ?Sized> {
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Completed<T> {
+    /// Creates a new [`Completed`] marker.
+    pub fn new() -> Self {
+        Self { _p1: PhantomData }
+    }
+}
+
+impl<T: ?Sized> Default for Completed<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Clone for Completed<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn completed_insert_observer<T: ?Sized + Send + Sync + 'static>(
+    marker: CompletedMarker,
+) -> impl FnMut(Trigger<Done<T>>, Commands) {
+    move |trigger, mut commands| {
+        // Empty trackers are done without having completed anything
+        if !trigger.event().has_completed() { return }
+
+        // Untargeted events are for the resource
+        let entity = trigger.entity();
+        if entity == Entity::PLACEHOLDER { return }
+
+        let mut entity = match commands.get_entity(entity) {
+            Some(v) => v,
+            None => return,
+        };
+
+        entity.try_insert(Completed::<T>::new());
+
+        if marker == CompletedMarker::Replace {
+            entity.remove::<Progress<T>>();
+        }
+    }
+}
+
+pub(crate) fn completed_remove_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Started<T>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+    if entity == Entity::PLACEHOLDER { return }
+
+    if let Some(mut entity) = commands.get_entity(entity) {
+        entity.remove::<Completed<T>>();
+    }
+}
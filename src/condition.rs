@@ -0,0 +1,30 @@
+use bevy_ecs::prelude::*;
+use crate::{Phase, Progress};
+
+/// Returns a run condition that is `true` if the [`Progress<T>`] resource was complete (or empty) when last checked.
+///
+/// This is `false` if the resource doesn't exist, or hasn't been checked yet.
+pub fn progress_done<T: ?Sized + Send + Sync + 'static>() -> impl FnMut(Option<Res<Progress<T>>>) -> bool + Clone {
+    |resource| resource.is_some_and(|v| checked_done(&v))
+}
+
+/// Returns a run condition that is `true` if the [`Progress<T>`] resource was incomplete when last checked.
+///
+/// This is the inverse of [`progress_done`], so it is `true` if the resource doesn't exist.
+pub fn progress_not_done<T: ?Sized + Send + Sync + 'static>() -> impl FnMut(Option<Res<Progress<T>>>) -> bool + Clone {
+    |resource| !resource.is_some_and(|v| checked_done(&v))
+}
+
+/// Returns a run condition that is `true` if the [`Progress<T>`] resource's fraction
+/// was at least `fract` when last checked.
+///
+/// This is `false` if the resource doesn't exist, or hasn't been checked yet.
+pub fn progress_at_least<T: ?Sized + Send + Sync + 'static>(fract: f32) -> impl FnMut(Option<Res<Progress<T>>>) -> bool + Clone {
+    move |resource| resource.is_some_and(|v| v.phase != Phase::Fresh && v.previous_fract >= fract)
+}
+
+// Uses the state from the last check, since the work recorded
+// so far this tick may be empty or incomplete.
+pub(crate) fn checked_done<T: ?Sized>(progress: &Progress<T>) -> bool {
+    matches!(progress.phase, Phase::Idle | Phase::Complete)
+}
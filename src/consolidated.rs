@@ -0,0 +1,82 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{BoxedCondition, InternedScheduleLabel}, system::BoxedSystem};
+use crate::{placement::SystemPlacement, tracking_enabled, ProgressPaused, ProgressSystems};
+
+/// Type-erased systems for trackers added in consolidated mode,
+/// run by a single exclusive system per schedule and set.
+#[derive(Resource, Default)]
+struct ConsolidatedSystems {
+    entries: Vec<ConsolidatedEntry>,
+}
+
+struct ConsolidatedEntry {
+    schedule: InternedScheduleLabel,
+    set: ProgressSystems,
+    conditions: Vec<BoxedCondition>,
+    system: BoxedSystem,
+}
+
+/// Adds `system` to the exclusive system that runs consolidated systems in `set`, only running it if `conditions` are met.
+pub(crate) fn add_system<M>(
+    app: &mut App,
+    schedule: InternedScheduleLabel,
+    set: ProgressSystems,
+    mut conditions: Vec<BoxedCondition>,
+    system: impl IntoSystem<(), (), M>,
+) {
+    let world = app.world_mut();
+    let mut system: BoxedSystem = Box::new(IntoSystem::into_system(system));
+    system.initialize(world);
+
+    for condition in &mut conditions {
+        condition.initialize(world);
+    }
+
+    let mut systems = world.get_resource_or_insert_with(ConsolidatedSystems::default);
+    let first = !systems.entries.iter().any(|entry| entry.schedule == schedule && entry.set == set);
+
+    systems.entries.push(ConsolidatedEntry {
+        schedule,
+        set,
+        conditions,
+        system,
+    });
+
+    if !first { return }
+
+    let run = move |world: &mut World| run_consolidated(world, schedule, set);
+
+    match set {
+        ProgressSystems::Reset => app.add_systems(schedule, run.in_set(set).after(ProgressSystems::Check)),
+        _ => app.add_systems(schedule, run.in_set(set)),
+    };
+}
+
+/// Returns the conditions shared by the consolidated systems of `T`.
+pub(crate) fn conditions<T: ?Sized + Send + Sync + 'static, M>(
+    placement: &SystemPlacement,
+    condition: impl Condition<M>,
+) -> Vec<BoxedCondition> {
+    let mut conditions = vec![
+        boxed(not(resource_exists::<ProgressPaused<T>>)),
+        boxed(tracking_enabled::<T>),
+        boxed(condition),
+    ];
+
+    conditions.extend(placement.conditions());
+    conditions
+}
+
+pub(crate) fn boxed<M>(condition: impl Condition<M>) -> BoxedCondition {
+    Box::new(IntoSystem::into_system(condition))
+}
+
+fn run_consolidated(world: &mut World, schedule: InternedScheduleLabel, set: ProgressSystems) {
+    world.resource_scope(|world, mut systems: Mut<ConsolidatedSystems>| {
+        for entry in systems.entries.iter_mut() {
+            if entry.schedule != schedule || entry.set != set { continue }
+            if !entry.conditions.iter_mut().all(|condition| condition.run((), world)) { continue }
+            entry.system.run((), world);
+        }
+    });
+}
@@ -0,0 +1,102 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use bevy_utils::{Duration, Instant};
+
+/// Conditions a tracker must meet before [`Done`](crate::Done) is raised.
+///
+/// This prevents trackers from completing for a single frame,
+/// before systems that run later have had a chance to record more work.
+/// Set on the tracking plugins with `with_debounce`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompletionDebounce {
+    /// How long the tracker must have been active for.
+    ///
+    /// This is measured from when the tracker started,
+    /// or from when it was first checked if it never received work.
+    pub min_duration: Duration,
+
+    /// How many consecutive checks the tracker must be done for.
+    pub frames: u32,
+}
+
+impl CompletionDebounce {
+    /// Creates a new [`CompletionDebounce`], which doesn't delay completion.
+    pub const fn new() -> Self {
+        Self {
+            min_duration: Duration::ZERO,
+            frames: 1,
+        }
+    }
+
+    /// Sets how long the tracker must have been active for.
+    pub fn with_min_duration(mut self, duration: Duration) -> Self {
+        self.min_duration = duration;
+        self
+    }
+
+    /// Sets how many consecutive checks the tracker must be done for.
+    pub fn with_frames(mut self, frames: u32) -> Self {
+        self.frames = frames;
+        self
+    }
+}
+
+impl Default for CompletionDebounce {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The [`CompletionDebounce`] used by the check systems for `T`.
+#[derive(Resource)]
+pub(crate) struct DebounceConfig<T: ?Sized> {
+    pub resource: CompletionDebounce,
+    pub entities: CompletionDebounce,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for DebounceConfig<T> {
+    fn default() -> Self {
+        Self {
+            resource: CompletionDebounce::new(),
+            entities: CompletionDebounce::new(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct DebounceState {
+    first_checked: Option<Instant>,
+    frames: u32,
+}
+
+impl DebounceState {
+    pub(crate) fn new() -> Self {
+        Self {
+            first_checked: None,
+            frames: 0,
+        }
+    }
+
+    /// Returns `true` if a tracker that is done now may raise [`Done`](crate::Done).
+    pub(crate) fn check(
+        &mut self,
+        debounce: &CompletionDebounce,
+        started_at: Option<Instant>,
+        done: bool,
+    ) -> bool {
+        let first_checked = *self.first_checked.get_or_insert_with(Instant::now);
+
+        if !done {
+            self.frames = 0;
+            return false;
+        }
+
+        self.frames = self.frames.saturating_add(1);
+        if self.frames < debounce.frames { return false }
+
+        started_at.unwrap_or(first_checked).elapsed() >= debounce.min_duration
+    }
+}
@@ -0,0 +1,76 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use crate::Done;
+
+#[cfg(feature="bevy_hierarchy")]
+use bevy_hierarchy::DespawnRecursiveExt;
+
+/// Despawns the entity when its [`Progress<T>`](crate::Progress) tracker completes.
+///
+/// Only trackers that complete recorded work despawn the entity,
+/// so an empty tracker that is checked before it receives any work doesn't.
+///
+/// The entity is despawned after all observers of [`Done<T>`] have run,
+/// so they can still access it. This is useful for transient entities,
+/// such as requests to load a chunk of terrain.
+///
+/// Handled by [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin).
+#[derive(Component)]
+pub struct DespawnOnDone<T: ?Sized> {
+    #[cfg(feature="bevy_hierarchy")]
+    recursive: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> DespawnOnDone<T> {
+    /// Creates a new [`DespawnOnDone`] component.
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature="bevy_hierarchy")]
+            recursive: false,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Also despawns all descendants of the entity.
+    #[cfg(feature="bevy_hierarchy")]
+    pub fn recursive() -> Self {
+        Self {
+            recursive: true,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Default for DespawnOnDone<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn despawn_on_done_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Done<T>>,
+    mut commands: Commands,
+    query: Query<&DespawnOnDone<T>>,
+) {
+    if !trigger.event().has_completed() { return }
+    let entity = trigger.entity();
+
+    // Also filters out untargeted events, which are for the resource
+    let despawn = match query.get(entity) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    #[cfg(feature="bevy_hierarchy")]
+    if despawn.recursive {
+        commands.entity(entity).despawn_recursive();
+        return;
+    }
+
+    #[cfg(not(feature="bevy_hierarchy"))]
+    let _ = despawn;
+
+    commands.entity(entity).despawn();
+}
@@ -0,0 +1,107 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{EtaEstimator, Progress, ProgressPaused, ProgressSystems};
+
+/// Publishes the state of the [`Progress<T>`] resource as diagnostics in the [`DiagnosticsStore`](bevy_diagnostic::DiagnosticsStore).
+///
+/// Diagnostics are published under `progress/<type>/`, where `<type>` is the short name of `T`.
+/// The rate of work is only published if an [`EtaEstimator<T>`] exists, such as from [`EtaPlugin<T>`](crate::EtaPlugin).
+pub struct ProgressDiagnosticsPlugin<T: ?Sized> {
+    /// The schedule in which diagnostics are measured.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressDiagnosticsPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> ProgressDiagnosticsPlugin<T> {
+    /// The path of the diagnostic for the fraction of work done, as returned by [`Progress::fract`].
+    pub fn fract_path() -> DiagnosticPath {
+        path::<T>("fract")
+    }
+
+    /// The path of the diagnostic for the units of work done.
+    pub fn done_path() -> DiagnosticPath {
+        path::<T>("done")
+    }
+
+    /// The path of the diagnostic for the total units of work.
+    pub fn total_path() -> DiagnosticPath {
+        path::<T>("total")
+    }
+
+    /// The path of the diagnostic for the rate of work, in units of work per second.
+    pub fn rate_path() -> DiagnosticPath {
+        path::<T>("rate")
+    }
+}
+
+fn path<T: ?Sized>(name: &str) -> DiagnosticPath {
+    let ty = bevy_utils::get_short_name(std::any::type_name::<T>());
+    DiagnosticPath::from_components(["progress", &ty, name])
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressDiagnosticsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::fract_path()).with_max_history_length(1));
+        app.register_diagnostic(Diagnostic::new(Self::done_path()).with_max_history_length(1));
+        app.register_diagnostic(Diagnostic::new(Self::total_path()).with_max_history_length(1));
+        app.register_diagnostic(Diagnostic::new(Self::rate_path()).with_suffix("/s"));
+
+        app.add_systems(self.schedule, diagnostics_system::<T>
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+struct DiagnosticPaths<T: ?Sized> {
+    fract: DiagnosticPath,
+    done: DiagnosticPath,
+    total: DiagnosticPath,
+    rate: DiagnosticPath,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for DiagnosticPaths<T> {
+    fn default() -> Self {
+        Self {
+            fract: ProgressDiagnosticsPlugin::<T>::fract_path(),
+            done: ProgressDiagnosticsPlugin::<T>::done_path(),
+            total: ProgressDiagnosticsPlugin::<T>::total_path(),
+            rate: ProgressDiagnosticsPlugin::<T>::rate_path(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+fn diagnostics_system<T: ?Sized + Send + Sync + 'static>(
+    mut diagnostics: Diagnostics,
+    paths: Local<DiagnosticPaths<T>>,
+    resource: Option<Res<Progress<T>>>,
+    estimator: Option<Res<EtaEstimator<T>>>,
+) {
+    let resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let (done, total) = resource.work();
+    diagnostics.add_measurement(&paths.fract, || resource.fract_f64());
+    diagnostics.add_measurement(&paths.done, || done as f64);
+    diagnostics.add_measurement(&paths.total, || total as f64);
+
+    if let Some(rate) = estimator.and_then(|v| v.rate()) {
+        diagnostics.add_measurement(&paths.rate, || rate);
+    }
+}
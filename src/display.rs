@@ -0,0 +1,66 @@
+use std::fmt::{self, Debug, Display};
+use crate::{Done, Progress};
+
+impl<T: ?Sized> Progress<T> {
+    /// Renders the progress as a text bar `width` characters wide, followed by a percentage.
+    ///
+    /// For example, a bar 10 characters wide at 52% renders as `[#####.....] 52%`.
+    pub fn render_bar(&self, width: usize) -> String {
+        let filled = ((self.fract_f64() * width as f64).floor() as usize).min(width);
+
+        let mut bar = String::with_capacity(width + 7);
+        bar.push('[');
+        bar.extend(std::iter::repeat_n('#', filled));
+        bar.extend(std::iter::repeat_n('.', width - filled));
+        bar.push(']');
+        bar.push_str(&format!(" {}%", self.permille() / 10));
+        bar
+    }
+}
+
+impl<T: ?Sized> Debug for Progress<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (done, total) = self.work();
+
+        f.debug_struct("Progress")
+            .field("done", &done)
+            .field("total", &total)
+            .field("fract", &self.fract())
+            .field("latch", &self.latch)
+            .field("phase", &self.phase)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized> Display for Progress<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (done, total) = self.work();
+        write!(f, "{done}/{total} ({}%)", self.permille() / 10)
+    }
+}
+
+impl<T: ?Sized> Debug for Done<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (done, total) = self.work();
+
+        f.debug_struct("Done")
+            .field("done", &done)
+            .field("total", &total)
+            .field("entity", &self.entity())
+            .field("elapsed", &self.elapsed())
+            .field("overshot", &self.overshot())
+            .field("completed", &self.has_completed())
+            .finish()
+    }
+}
+
+impl<T: ?Sized> Display for Done<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (done, total) = self.work();
+
+        match self.entity() {
+            Some(entity) => write!(f, "{entity} done ({done}/{total})"),
+            None => write!(f, "done ({done}/{total})"),
+        }
+    }
+}
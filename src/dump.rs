@@ -0,0 +1,67 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::info;
+use crate::{ProgressInfo, ProgressRegistry, ProgressSystems};
+
+/// An observer event that logs the state of every tracker in the [`ProgressRegistry`] at the `info` level.
+///
+/// This includes the work, fraction, and stall status of each tracker,
+/// and the rate of work if an [`EtaEstimator`](crate::EtaEstimator) exists.
+/// Logging is deferred until after [`ProgressSystems::Check`] in [`PostUpdate`],
+/// so all work recorded during the tick is included.
+///
+/// This is useful to bind to a debug key to diagnose loads that don't finish.
+#[derive(Debug, Default, Clone, Copy, Event)]
+pub struct DumpProgress;
+
+#[derive(Resource)]
+struct DumpRequested;
+
+pub(crate) fn setup(app: &mut App) {
+    app.observe(dump_observer);
+
+    app.add_systems(PostUpdate, dump_system
+        .after(ProgressSystems::Check)
+        .run_if(resource_exists::<DumpRequested>));
+}
+
+fn dump_observer(
+    _trigger: Trigger<DumpProgress>,
+    mut commands: Commands,
+) {
+    commands.insert_resource(DumpRequested);
+}
+
+fn dump_system(world: &mut World) {
+    world.remove_resource::<DumpRequested>();
+
+    let registry = match world.get_resource::<ProgressRegistry>() {
+        Some(v) => v,
+        None => return,
+    };
+
+    info!("dumping {} progress trackers", registry.iter().count());
+
+    for entry in registry.iter() {
+        let name = entry.type_name();
+
+        if let Some(info) = entry.resource(world) {
+            let rate = match entry.rate(world) {
+                Some(rate) => format!(", {rate:.2}/s"),
+                None => String::new(),
+            };
+
+            info!("{name}: {}{rate}", describe(&info));
+        }
+
+        for (entity, info) in entry.entities(world) {
+            info!("{name} on {entity}: {}", describe(&info));
+        }
+    }
+}
+
+fn describe(info: &ProgressInfo) -> String {
+    let (done, total) = info.work();
+    let stalled = if info.stalled() { ", stalled" } else { "" };
+    format!("{done}/{total} ({:.1}%){stalled}", info.fract() * 100.0)
+}
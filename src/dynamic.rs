@@ -0,0 +1,317 @@
+use std::borrow::Cow;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_utils::{Duration, HashMap};
+use crate::{debounce::DebounceConfig, tracking_enabled, Progress, ProgressPaused, ProgressSystems, ResetPolicy};
+
+/// Adds progress tracking for the [`DynamicProgress`] resource.
+///
+/// Trackers in the resource are checked and reset like [`Progress<T>`] resources,
+/// raising events with the key of the tracker instead of the usual events:
+/// [`DynamicStarted`], [`DynamicUpdated`], [`DynamicStalled`], [`DynamicFailed`], and [`DynamicDone`].
+/// Tracking can be paused by inserting [`ProgressPaused<DynamicProgress>`],
+/// or disabled with [`ProgressTrackingEnabled<DynamicProgress>`](crate::ProgressTrackingEnabled).
+pub struct DynamicProgressPlugin {
+    /// The schedule in which the progress values are checked.
+    pub check_schedule: InternedScheduleLabel,
+
+    /// The schedule in which the progress values are reset.
+    /// This should be the same as, or after, `check_schedule`.
+    pub reset_schedule: InternedScheduleLabel,
+
+    /// When trackers are reset.
+    pub reset_policy: ResetPolicy,
+}
+
+impl Default for DynamicProgressPlugin {
+    fn default() -> Self {
+        Self {
+            check_schedule: PostUpdate.intern(),
+            reset_schedule: Last.intern(),
+            reset_policy: ResetPolicy::EveryTick,
+        }
+    }
+}
+
+impl Plugin for DynamicProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DynamicProgress>();
+
+        app.add_systems(self.check_schedule, dynamic_progress_check_system
+            .in_set(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<DynamicProgress>>))
+            .run_if(tracking_enabled::<DynamicProgress>));
+
+        app.add_systems(self.reset_schedule, dynamic_progress_reset_system(self.reset_policy)
+            .in_set(ProgressSystems::Reset)
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<DynamicProgress>>))
+            .run_if(tracking_enabled::<DynamicProgress>));
+    }
+}
+
+fn dynamic_progress_check_system(
+    mut commands: Commands,
+    mut resource: ResMut<DynamicProgress>,
+    debounce: Option<Res<DebounceConfig<DynamicProgress>>>,
+) {
+    let debounce = debounce.map(|v| v.resource).unwrap_or_default();
+
+    for (key, tracker) in resource.bypass_change_detection().trackers.iter_mut() {
+        let checked = tracker.check_with(&debounce);
+
+        if checked.started {
+            commands.trigger(DynamicStarted::new(key.clone(), tracker));
+        }
+
+        if checked.updated {
+            commands.trigger(DynamicUpdated::new(key.clone(), tracker, checked.previous));
+        }
+
+        if let Some((frames, duration)) = checked.stalled {
+            commands.trigger(DynamicStalled { key: key.clone(), frames, duration });
+        }
+
+        if checked.failed {
+            commands.trigger(DynamicFailed::new(key.clone(), tracker));
+        }
+
+        if checked.done {
+            commands.trigger(DynamicDone::new(key.clone(), tracker));
+        }
+    }
+}
+
+fn dynamic_progress_reset_system(
+    policy: ResetPolicy,
+) -> impl FnMut(ResMut<DynamicProgress>) {
+    move |mut resource| {
+        // Only trackers that need resetting are touched, so the resource isn't always marked as changed
+        if !resource.trackers.values().any(|tracker| tracker.needs_reset(policy)) { return }
+
+        for tracker in resource.trackers.values_mut() {
+            if !tracker.needs_reset(policy) { continue }
+            tracker.reset_with(policy);
+        }
+    }
+}
+
+/// A set of progress trackers identified by strings, rather than by types.
+///
+/// This is intended for scripting layers and mod loaders, which can't define their own types.
+/// Requires [`DynamicProgressPlugin`].
+#[derive(Resource, Default)]
+pub struct DynamicProgress {
+    trackers: HashMap<Cow<'static, str>, Progress<DynamicProgress>>,
+}
+
+impl DynamicProgress {
+    /// Creates a new, empty [`DynamicProgress`] resource.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a tracker for `key`, replacing any existing tracker.
+    pub fn insert(&mut self, key: impl Into<Cow<'static, str>>, tracker: Progress<DynamicProgress>) {
+        self.trackers.insert(key.into(), tracker);
+    }
+
+    /// Removes the tracker for `key`, returning it if it existed.
+    pub fn remove(&mut self, key: &str) -> Option<Progress<DynamicProgress>> {
+        self.trackers.remove(key)
+    }
+
+    /// Returns the tracker for `key`, if it exists.
+    pub fn get(&self, key: &str) -> Option<&Progress<DynamicProgress>> {
+        self.trackers.get(key)
+    }
+
+    /// Returns the tracker for `key` mutably, if it exists.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Progress<DynamicProgress>> {
+        self.trackers.get_mut(key)
+    }
+
+    /// Returns the tracker for `key`, creating it if it doesn't exist.
+    pub fn entry(&mut self, key: impl Into<Cow<'static, str>>) -> &mut Progress<DynamicProgress> {
+        self.trackers.entry(key.into()).or_default()
+    }
+
+    /// Records progress for `key`, creating its tracker if it doesn't exist.
+    pub fn track(&mut self, key: impl Into<Cow<'static, str>>, done: u32, total: u32) {
+        self.entry(key).track(done, total);
+    }
+
+    /// Returns an iterator over all keys and their trackers.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Progress<DynamicProgress>)> {
+        self.trackers.iter().map(|(key, tracker)| (key.as_ref(), tracker))
+    }
+}
+
+/// An observer event raised when a tracker in [`DynamicProgress`] first receives work.
+#[derive(Event)]
+pub struct DynamicStarted {
+    key: Cow<'static, str>,
+    done: u64,
+    total: u64,
+}
+
+impl DynamicStarted {
+    fn new(key: Cow<'static, str>, progress: &Progress<DynamicProgress>) -> Self {
+        let (done, total) = progress.work();
+        Self { key, done, total }
+    }
+
+    /// Returns the key of the tracker that started.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the work that had been completed and the total units of work when the tracker started.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+}
+
+/// An observer event raised when the work recorded by a tracker in [`DynamicProgress`] changes between checks.
+#[derive(Event)]
+pub struct DynamicUpdated {
+    key: Cow<'static, str>,
+    done: u64,
+    total: u64,
+    delta_done: i64,
+    delta_total: i64,
+    fract: f32,
+}
+
+impl DynamicUpdated {
+    fn new(key: Cow<'static, str>, progress: &Progress<DynamicProgress>, previous: (u64, u64)) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            key,
+            done,
+            total,
+            delta_done: done as i64 - previous.0 as i64,
+            delta_total: total as i64 - previous.1 as i64,
+            fract: progress.fract(),
+        }
+    }
+
+    /// Returns the key of the tracker that was updated.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the work that has been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the change in completed work and total work since the last check.
+    #[inline]
+    pub fn delta(&self) -> (i64, i64) {
+        (self.delta_done, self.delta_total)
+    }
+
+    /// Returns the new progress as a fraction, as returned by [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+}
+
+/// An observer event raised when a tracker in [`DynamicProgress`] stops making progress,
+/// as configured with [`Progress::with_stall_threshold`].
+#[derive(Event)]
+pub struct DynamicStalled {
+    key: Cow<'static, str>,
+    frames: u32,
+    duration: Duration,
+}
+
+impl DynamicStalled {
+    /// Returns the key of the tracker that stalled.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the number of checks the tracker has gone without progress.
+    #[inline]
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Returns how long the tracker has gone without progress.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// An observer event raised when more work has failed in a tracker in [`DynamicProgress`] since it was last checked.
+#[derive(Event)]
+pub struct DynamicFailed {
+    key: Cow<'static, str>,
+    failed: u64,
+    reasons: Vec<Cow<'static, str>>,
+}
+
+impl DynamicFailed {
+    fn new(key: Cow<'static, str>, progress: &Progress<DynamicProgress>) -> Self {
+        Self {
+            key,
+            failed: progress.failed(),
+            reasons: progress.failure_reasons().to_vec(),
+        }
+    }
+
+    /// Returns the key of the tracker that failed.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the units of work that have failed.
+    #[inline]
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+
+    /// Returns the reasons given for failed work, in the order they were recorded.
+    #[inline]
+    pub fn reasons(&self) -> &[Cow<'static, str>] {
+        &self.reasons
+    }
+}
+
+/// An observer event raised when a tracker in [`DynamicProgress`] completes.
+#[derive(Event)]
+pub struct DynamicDone {
+    key: Cow<'static, str>,
+    done: u64,
+    total: u64,
+}
+
+impl DynamicDone {
+    fn new(key: Cow<'static, str>, progress: &Progress<DynamicProgress>) -> Self {
+        let (done, total) = progress.work();
+        Self { key, done, total }
+    }
+
+    /// Returns the key of the tracker that completed.
+    #[inline]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the work that had been completed and the total units of work at completion.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+}
@@ -0,0 +1,136 @@
+use std::{collections::VecDeque, marker::PhantomData};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_utils::{Duration, Instant};
+use crate::{Progress, ProgressPaused, ProgressSystems};
+
+/// Maintains [`EtaEstimator<T>`] resources and components.
+///
+/// The [`EtaEstimator<T>`] resource is added by the plugin.
+/// Entities can be given an [`EtaEstimator<T>`] component to estimate their own [`Progress<T>`] component.
+pub struct EtaPlugin<T: ?Sized> {
+    /// The schedule in which progress is sampled.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// How far back samples are kept for estimating the rate of work.
+    pub window: Duration,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for EtaPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            window: Duration::from_secs(5),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for EtaPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EtaEstimator::<T>::new(self.window));
+
+        app.add_systems(self.schedule, (
+            eta_sample_system::<T>,
+            entity_eta_sample_system::<T>,
+        ).in_set(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+fn eta_sample_system<T: ?Sized + Send + Sync + 'static>(
+    mut estimator: ResMut<EtaEstimator<T>>,
+    resource: Option<Res<Progress<T>>>,
+) {
+    let resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    estimator.sample(resource.work(), Instant::now());
+}
+
+fn entity_eta_sample_system<T: ?Sized + Send + Sync + 'static>(
+    mut query: Query<(&Progress<T>, &mut EtaEstimator<T>)>,
+) {
+    let now = Instant::now();
+
+    for (tracker, mut estimator) in &mut query {
+        estimator.sample(tracker.work(), now);
+    }
+}
+
+/// Estimates the rate of work and the time remaining for a [`Progress<T>`] tracker.
+///
+/// Can be inserted as a [`Resource`] to estimate the [`Progress<T>`] resource,
+/// or as a [`Component`] to estimate the [`Progress<T>`] component on the same entity.
+/// The rate is measured over a sliding window of recent samples.
+/// Maintained by [`EtaPlugin<T>`].
+#[derive(Component, Resource)]
+pub struct EtaEstimator<T: ?Sized> {
+    window: Duration,
+    samples: VecDeque<(Instant, u64)>,
+    remaining: u64,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> EtaEstimator<T> {
+    /// Creates a new [`EtaEstimator`], measuring over `window`.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+            remaining: 0,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the rate of work, in units of done work per second.
+    ///
+    /// Returns `None` if there aren't enough samples to estimate the rate.
+    pub fn rate(&self) -> Option<f64> {
+        let (first_at, first) = *self.samples.front()?;
+        let (last_at, last) = *self.samples.back()?;
+
+        let elapsed = last_at.duration_since(first_at).as_secs_f64();
+        if elapsed <= 0.0 { return None }
+
+        Some((last - first) as f64 / elapsed)
+    }
+
+    /// Returns the estimated time until all work is done, based on the current [`rate`](Self::rate).
+    ///
+    /// Returns `None` if the rate can't be estimated, or no work is being done.
+    pub fn estimated_remaining(&self) -> Option<Duration> {
+        if self.remaining == 0 { return Some(Duration::ZERO) }
+
+        let rate = self.rate()?;
+        if rate <= 0.0 { return None }
+
+        Duration::try_from_secs_f64(self.remaining as f64 / rate).ok()
+    }
+
+    /// Discards all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.remaining = 0;
+    }
+
+    fn sample(&mut self, (done, total): (u64, u64), now: Instant) {
+        // Less done work means a new set of work, so old samples are meaningless
+        if self.samples.back().is_some_and(|(_, last)| done < *last) {
+            self.samples.clear();
+        }
+
+        self.samples.push_back((now, done));
+        self.remaining = total.saturating_sub(done);
+
+        while let Some((at, _)) = self.samples.front() {
+            if now.duration_since(*at) <= self.window { break }
+            self.samples.pop_front();
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Counts occurrences of the event `E` as completed work in the [`Progress<T>`] resource.
+///
+/// Each tick, the number of `E` events read so far is recorded as done work, out of `expected` units of total work.
+/// This lets systems that already send events, such as one per generated chunk, contribute to progress.
+///
+/// `E` must be added to the app with [`add_event`](App::add_event).
+pub struct EventCountPlugin<T: ?Sized, E> {
+    /// The schedule in which events are counted.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// The total number of events expected.
+    pub expected: u32,
+
+    _p1: PhantomData<T>,
+    _p2: PhantomData<E>,
+}
+
+impl<T: ?Sized, E> EventCountPlugin<T, E> {
+    /// Creates a new [`EventCountPlugin`], expecting `expected` events in total.
+    pub fn new(expected: u32) -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            expected,
+            _p1: PhantomData,
+            _p2: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, E: Event> Plugin for EventCountPlugin<T, E> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EventCount::<T, E> {
+            count: 0,
+            expected: self.expected,
+            _p1: PhantomData,
+            _p2: PhantomData,
+        });
+
+        app.add_systems(self.schedule, event_count_system::<T, E>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+#[derive(Resource)]
+struct EventCount<T: ?Sized, E> {
+    count: u64,
+    expected: u32,
+    _p1: PhantomData<T>,
+    _p2: PhantomData<E>,
+}
+
+fn event_count_system<T: ?Sized + Send + Sync + 'static, E: Event>(
+    mut state: ResMut<EventCount<T, E>>,
+    mut events: EventReader<E>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    state.count += events.read().count() as u64;
+
+    if let Some(mut resource) = resource {
+        record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+            progress.track_u64(state.count, state.expected as u64);
+        });
+    }
+}
@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_render::{Extract, ExtractSchedule, RenderApp};
+use crate::Progress;
+
+/// Copies [`Progress<T>`] trackers into the render world every frame, as [`ExtractedProgress<T>`].
+///
+/// This lets loading screen shaders and materials read progress from render world systems,
+/// even when the main world is busy with the next frame under pipelined rendering.
+/// The resource is extracted as a resource, and components onto the matching render world entities.
+///
+/// This must be added after `RenderPlugin`, and does nothing if there is no render sub-app.
+pub struct ExtractProgressPlugin<T: ?Sized> {
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ExtractProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ExtractProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Some(v) => v,
+            None => return,
+        };
+
+        render_app.add_systems(ExtractSchedule, (
+            resource_extract_system::<T>,
+            entity_extract_system::<T>,
+        ));
+    }
+}
+
+fn resource_extract_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Extract<Option<Res<Progress<T>>>>,
+) {
+    match resource.as_deref() {
+        Some(resource) => commands.insert_resource(ExtractedProgress::<T>::new(resource)),
+        None => commands.remove_resource::<ExtractedProgress<T>>(),
+    }
+}
+
+fn entity_extract_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut previous_len: Local<usize>,
+    query: Extract<Query<(Entity, &Progress<T>)>>,
+) {
+    let mut values = Vec::with_capacity(*previous_len);
+
+    for (entity, tracker) in &query {
+        values.push((entity, ExtractedProgress::<T>::new(tracker)));
+    }
+
+    *previous_len = values.len();
+    commands.insert_or_spawn_batch(values);
+}
+
+/// A copy of a [`Progress<T>`] tracker in the render world, extracted by [`ExtractProgressPlugin<T>`].
+///
+/// Inserted as a [`Resource`] for the [`Progress<T>`] resource,
+/// and as a [`Component`] for [`Progress<T>`] components, on the render world entity
+/// with the same ID as the main world entity.
+#[derive(Component, Resource)]
+pub struct ExtractedProgress<T: ?Sized> {
+    fract: f32,
+    done: u64,
+    total: u64,
+    complete: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ExtractedProgress<T> {
+    fn new(progress: &Progress<T>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            fract: progress.fract(),
+            done,
+            total,
+            complete: progress.is_done(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the progress as a fraction, as in [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns the work that had been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns `true` if the tracker was done when extracted.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.complete
+    }
+}
+
+impl<T: ?Sized> Clone for ExtractedProgress<T> {
+    fn clone(&self) -> Self {
+        Self {
+            fract: self.fract,
+            done: self.done,
+            total: self.total,
+            complete: self.complete,
+            _p1: PhantomData,
+        }
+    }
+}
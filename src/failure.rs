@@ -0,0 +1,119 @@
+use std::{borrow::Cow, marker::PhantomData};
+use bevy_ecs::prelude::*;
+use crate::Progress;
+
+impl<T: ?Sized> Progress<T> {
+    /// Records units of work that have failed.
+    ///
+    /// Failed work counts toward the total, but never toward done work,
+    /// so a tracker with failures will not complete until its failures are no longer recorded.
+    pub fn track_failed(&mut self, failed: u32) {
+        self.record(0, failed as u64, 1.0);
+        self.failure.failed += failed as u64;
+    }
+
+    /// Records a single unit of failed work, with a human-readable reason.
+    ///
+    /// See [`track_failed`](Self::track_failed) for how failed work is counted.
+    pub fn fail_with(&mut self, reason: impl Into<Cow<'static, str>>) {
+        self.track_failed(1);
+        self.failure.reasons.push(reason.into());
+    }
+
+    /// Records the outcome of one unit of work, counting `Ok` as done work and `Err` as failed work.
+    pub fn track_result<V, E>(&mut self, result: &Result<V, E>) {
+        match result {
+            Ok(_) => self.record(1, 1, 1.0),
+            Err(_) => self.track_failed(1),
+        }
+    }
+
+    /// Returns the units of work that have failed.
+    #[inline]
+    pub fn failed(&self) -> u64 {
+        self.failure.failed
+    }
+
+    /// Removes failed work from the tracker, so new failures raise [`Failed`] again.
+    pub(crate) fn clear_failures(&mut self) {
+        self.unrecord(0, self.failure.failed, 1.0);
+        self.failure = FailureState::new();
+    }
+
+    /// Returns the reasons given for failed work, in the order they were recorded.
+    #[inline]
+    pub fn failure_reasons(&self) -> &[Cow<'static, str>] {
+        &self.failure.reasons
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct FailureState {
+    pub(crate) failed: u64,
+    pub(crate) reasons: Vec<Cow<'static, str>>,
+    previous: u64,
+}
+
+impl FailureState {
+    pub(crate) fn new() -> Self {
+        Self {
+            failed: 0,
+            reasons: Vec::new(),
+            previous: 0,
+        }
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.failed = 0;
+        self.reasons.clear();
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.failed == 0 && self.reasons.is_empty()
+    }
+
+    /// Returns `true` if more work has failed since the last check.
+    pub(crate) fn check(&mut self) -> bool {
+        let failed = self.failed > self.previous;
+        self.previous = self.failed;
+        failed
+    }
+}
+
+/// An observer event raised when more work has failed in a progress tracker since it was last checked.
+#[derive(Event)]
+pub struct Failed<T: ?Sized> {
+    failed: u64,
+    reasons: Vec<Cow<'static, str>>,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Failed<T> {
+    pub(crate) fn new(progress: &Progress<T>, entity: Option<Entity>) -> Self {
+        Self {
+            failed: progress.failure.failed,
+            reasons: progress.failure.reasons.clone(),
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the units of work that have failed.
+    #[inline]
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+
+    /// Returns the reasons given for failed work, in the order they were recorded.
+    #[inline]
+    pub fn reasons(&self) -> &[Cow<'static, str>] {
+        &self.reasons
+    }
+
+    /// Returns the entity whose tracker failed, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
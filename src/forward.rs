@@ -0,0 +1,83 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Mirrors the work recorded by the [`Progress<F>`] resource into the [`Progress<T>`] resource.
+///
+/// This lets libraries record progress with their own types, which applications
+/// can then fold into their own trackers. Work is forwarded as of the last check of `F`,
+/// so the forwarded work lags a tick behind.
+pub struct ForwardProgress<F: ?Sized, T: ?Sized> {
+    /// The schedule in which work is forwarded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// The weight of forwarded work, as in [`Progress::track_weighted`].
+    pub weight: f32,
+
+    _p1: PhantomData<F>,
+    _p2: PhantomData<T>,
+}
+
+impl<F: ?Sized, T: ?Sized> Default for ForwardProgress<F, T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            weight: 1.0,
+            _p1: PhantomData,
+            _p2: PhantomData,
+        }
+    }
+}
+
+impl<F: ?Sized, T: ?Sized> ForwardProgress<F, T> {
+    /// Sets the weight of forwarded work, as in [`Progress::track_weighted`].
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl<F: Send + Sync + 'static, T: Send + Sync + 'static> Plugin for ForwardProgress<F, T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ForwardWeight::<F, T> {
+            weight: self.weight as f64,
+            _p1: PhantomData,
+            _p2: PhantomData,
+        });
+
+        app.add_systems(self.schedule, forward_progress_system::<F, T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+#[derive(Resource)]
+struct ForwardWeight<F: ?Sized, T: ?Sized> {
+    weight: f64,
+    _p1: PhantomData<F>,
+    _p2: PhantomData<T>,
+}
+
+fn forward_progress_system<F: ?Sized + Send + Sync + 'static, T: ?Sized + Send + Sync + 'static>(
+    forward: Res<ForwardWeight<F, T>>,
+    source: Option<Res<Progress<F>>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let (source, mut resource) = match (source, resource) {
+        (Some(source), Some(resource)) => (source, resource),
+        _ => return,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        let (done, total) = source.previous;
+        progress.record(done, total, forward.weight);
+
+        let (done, total) = source.previous_float;
+        progress.track_f64(done * forward.weight, total * forward.weight);
+    });
+}
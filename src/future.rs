@@ -0,0 +1,196 @@
+use std::{future::Future, marker::PhantomData, pin::Pin, sync::{Arc, Mutex}, task::{Context, Poll, Waker}};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_tasks::futures_lite::Stream;
+use crate::{condition::checked_done, Done, Phase, Progress, ProgressSystems};
+
+/// Fulfills [`DoneFuture<T>`] and [`ProgressStream<T>`] handles created from the [`ProgressWatchers<T>`] resource.
+pub struct ProgressFuturePlugin<T: ?Sized> {
+    /// The schedule in which streams are updated.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressFuturePlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressFuturePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProgressWatchers<T>>();
+        app.observe(done_future_observer::<T>);
+
+        app.add_systems(self.schedule, progress_stream_system::<T>
+            .after(ProgressSystems::Check));
+    }
+}
+
+fn done_future_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Done<T>>,
+    mut watchers: ResMut<ProgressWatchers<T>>,
+) {
+    let event = trigger.event();
+    if event.entity().is_some() { return }
+
+    for slot in watchers.done.drain(..) {
+        slot.lock().unwrap().send(event.work());
+    }
+}
+
+fn progress_stream_system<T: ?Sized + Send + Sync + 'static>(
+    mut watchers: ResMut<ProgressWatchers<T>>,
+    resource: Option<Res<Progress<T>>>,
+) {
+    let resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    if resource.phase == Phase::Fresh { return }
+
+    let fract = match checked_done(&resource) {
+        true => 1.0,
+        false => resource.previous_fract,
+    };
+
+    if watchers.last == Some(fract) { return }
+    watchers.last = Some(fract);
+
+    // Streams that have been dropped don't need updates
+    watchers.streams.retain(|slot| Arc::strong_count(slot) > 1);
+
+    for slot in &watchers.streams {
+        slot.lock().unwrap().send(fract);
+    }
+}
+
+/// Creates futures and streams that are fulfilled by [`ProgressFuturePlugin<T>`],
+/// so async tasks can wait on the [`Progress<T>`] resource.
+#[derive(Resource)]
+pub struct ProgressWatchers<T: ?Sized> {
+    done: Vec<SharedSlot<(u64, u64)>>,
+    streams: Vec<SharedSlot<f32>>,
+    last: Option<f32>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressWatchers<T> {
+    /// Returns a future that resolves the next time [`Done<T>`] is raised for the resource.
+    pub fn done(&mut self) -> DoneFuture<T> {
+        let slot = Arc::new(Mutex::new(Slot::new(None)));
+        self.done.push(slot.clone());
+
+        DoneFuture {
+            slot,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns a stream of the resource's fraction each time it changes, as of its last check.
+    ///
+    /// Only the latest fraction is kept, so a slow consumer skips intermediate values.
+    /// The stream starts with the current fraction, if the resource has been checked.
+    pub fn stream(&mut self) -> ProgressStream<T> {
+        let slot = Arc::new(Mutex::new(Slot::new(self.last)));
+        self.streams.push(slot.clone());
+
+        ProgressStream {
+            slot,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Default for ProgressWatchers<T> {
+    fn default() -> Self {
+        Self {
+            done: Vec::new(),
+            streams: Vec::new(),
+            last: None,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for ProgressWatchers<T> {
+    fn drop(&mut self) {
+        for slot in &self.done { slot.lock().unwrap().close() }
+        for slot in &self.streams { slot.lock().unwrap().close() }
+    }
+}
+
+/// A future that resolves when the [`Progress<T>`] resource completes, created by [`ProgressWatchers::done`].
+///
+/// Outputs the work recorded when the tracker completed,
+/// or `None` if the [`ProgressWatchers<T>`] resource was removed first.
+pub struct DoneFuture<T: ?Sized> {
+    slot: SharedSlot<(u64, u64)>,
+    _p1: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized> Future for DoneFuture<T> {
+    type Output = Option<(u64, u64)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.slot.lock().unwrap().poll(cx)
+    }
+}
+
+/// A stream of the fraction of the [`Progress<T>`] resource, created by [`ProgressWatchers::stream`].
+///
+/// Ends when the [`ProgressWatchers<T>`] resource is removed.
+pub struct ProgressStream<T: ?Sized> {
+    slot: SharedSlot<f32>,
+    _p1: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized> Stream for ProgressStream<T> {
+    type Item = f32;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.slot.lock().unwrap().poll(cx)
+    }
+}
+
+type SharedSlot<V> = Arc<Mutex<Slot<V>>>;
+
+struct Slot<V> {
+    value: Option<V>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl<V> Slot<V> {
+    fn new(value: Option<V>) -> Self {
+        Self {
+            value,
+            closed: false,
+            waker: None,
+        }
+    }
+
+    fn send(&mut self, value: V) {
+        self.value = Some(value);
+        if let Some(waker) = self.waker.take() { waker.wake() }
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        if let Some(waker) = self.waker.take() { waker.wake() }
+    }
+
+    fn poll(&mut self, cx: &mut Context<'_>) -> Poll<Option<V>> {
+        if let Some(value) = self.value.take() { return Poll::Ready(Some(value)) }
+        if self.closed { return Poll::Ready(None) }
+
+        self.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
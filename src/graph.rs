@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{ProgressPaused, ProgressSystems, ProgressTask};
+
+/// Blocks [`ProgressTask<T>`] entities until the tasks they [depend on](DependsOn) are done.
+///
+/// Tasks with unfinished prerequisites are marked with [`TaskBlocked<T>`], which systems doing
+/// the work can use to skip them. Blocked tasks still count towards the [`Progress<T>`](crate::Progress)
+/// resource when used with [`ProgressTaskPlugin<T>`](crate::ProgressTaskPlugin), so the resource covers the whole graph.
+///
+/// Tasks in a dependency cycle are blocked forever.
+pub struct TaskGraphPlugin<T: ?Sized> {
+    /// The schedule in which tasks are blocked and unblocked.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for TaskGraphPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for TaskGraphPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, task_graph_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+fn task_graph_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    tasks: Query<&ProgressTask<T>>,
+    dependents: Query<(Entity, &DependsOn), With<ProgressTask<T>>>,
+    blocked: Query<(), With<TaskBlocked<T>>>,
+) {
+    for (entity, depends) in &dependents {
+        // Prerequisites that no longer exist are considered done
+        let runnable = depends.0.iter().all(|&prerequisite| match tasks.get(prerequisite) {
+            Ok(task) => task.is_done(),
+            Err(_) => true,
+        });
+
+        match (runnable, blocked.contains(entity)) {
+            (true, true) => { commands.entity(entity).remove::<TaskBlocked<T>>(); },
+            (false, false) => { commands.entity(entity).insert(TaskBlocked::<T>::new()); },
+            _ => {},
+        }
+    }
+}
+
+/// Declares that a task entity can't start until the task entities it lists are done.
+///
+/// Requires [`TaskGraphPlugin<T>`].
+#[derive(Debug, Clone, Component)]
+pub struct DependsOn(pub Vec<Entity>);
+
+impl DependsOn {
+    /// Creates a new [`DependsOn`] with a single prerequisite.
+    pub fn new(prerequisite: Entity) -> Self {
+        Self(vec![prerequisite])
+    }
+
+    /// Adds another prerequisite.
+    pub fn and(mut self, prerequisite: Entity) -> Self {
+        self.0.push(prerequisite);
+        self
+    }
+}
+
+/// A marker for [`ProgressTask<T>`] entities whose [prerequisites](DependsOn) are not yet done.
+///
+/// Maintained by [`TaskGraphPlugin<T>`].
+#[derive(Component)]
+pub struct TaskBlocked<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> TaskBlocked<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{Phase, Progress, ProgressPaused, ProgressSystems};
+
+/// Tracks whether a group of entities, such as one per connected player, are all done.
+///
+/// Members of the group are entities with both [`GroupMember<T>`] and [`Progress<T>`].
+/// After trackers are checked, the [`GroupReadiness<T>`] resource is updated,
+/// and [`AllDone<T>`] is raised when the group becomes ready according to the [`GroupQuorum`].
+///
+/// This requires [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin) to be added.
+pub struct GroupReadinessPlugin<T: ?Sized> {
+    /// The schedule in which readiness is evaluated.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// How many members must be done for the group to be ready.
+    pub quorum: GroupQuorum,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for GroupReadinessPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            quorum: GroupQuorum::All,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> GroupReadinessPlugin<T> {
+    /// Sets how many members must be done for the group to be ready.
+    pub fn with_quorum(mut self, quorum: GroupQuorum) -> Self {
+        self.quorum = quorum;
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for GroupReadinessPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GroupReadiness::<T>::new(self.quorum));
+
+        app.add_systems(self.schedule, group_readiness_system::<T>
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+/// How many members of a group must be done for the group to be ready.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupQuorum {
+    /// Every member must be done.
+    All,
+
+    /// At least one member must be done.
+    Any,
+
+    /// At least this many members must be done.
+    AtLeast(usize),
+}
+
+impl GroupQuorum {
+    fn reached(self, ready: usize, members: usize) -> bool {
+        if members == 0 { return false }
+
+        match self {
+            Self::All => ready == members,
+            Self::Any => ready > 0,
+            Self::AtLeast(count) => ready >= count,
+        }
+    }
+}
+
+/// Marks an entity with a [`Progress<T>`] tracker as a member of the group for `T`.
+///
+/// Used by [`GroupReadinessPlugin<T>`].
+#[derive(Component)]
+pub struct GroupMember<T: ?Sized> {
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> GroupMember<T> {
+    /// Creates a new [`GroupMember`] marker.
+    pub fn new() -> Self {
+        Self { _p1: PhantomData }
+    }
+}
+
+impl<T: ?Sized> Default for GroupMember<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The readiness of the group for `T`, as of the last time it was evaluated.
+///
+/// Updated by [`GroupReadinessPlugin<T>`].
+#[derive(Resource)]
+pub struct GroupReadiness<T: ?Sized> {
+    quorum: GroupQuorum,
+    ready: usize,
+    members: usize,
+    reached: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> GroupReadiness<T> {
+    fn new(quorum: GroupQuorum) -> Self {
+        Self {
+            quorum,
+            ready: 0,
+            members: 0,
+            reached: false,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the number of members that are done, and the total number of members.
+    #[inline]
+    pub fn members(&self) -> (usize, usize) {
+        (self.ready, self.members)
+    }
+
+    /// Returns `true` if the group is ready, according to its [`GroupQuorum`].
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.reached
+    }
+
+    /// Returns the [`GroupQuorum`] of the group.
+    #[inline]
+    pub fn quorum(&self) -> GroupQuorum {
+        self.quorum
+    }
+}
+
+fn group_readiness_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut readiness: ResMut<GroupReadiness<T>>,
+    query: Query<&Progress<T>, With<GroupMember<T>>>,
+) {
+    let members = query.iter().len();
+    let ready = query.iter()
+        .filter(|tracker| matches!(tracker.phase, Phase::Idle | Phase::Complete))
+        .count();
+
+    let reached = readiness.quorum.reached(ready, members);
+    let became_ready = reached && !readiness.reached;
+
+    // Avoid triggering change detection when nothing changed
+    if readiness.members != members || readiness.ready != ready || readiness.reached != reached {
+        readiness.members = members;
+        readiness.ready = ready;
+        readiness.reached = reached;
+    }
+
+    if became_ready {
+        commands.trigger(AllDone::<T>::new(ready, members));
+    }
+}
+
+/// An observer event raised when a group of entities becomes ready.
+///
+/// Despite the name, this respects the [`GroupQuorum`], so with [`GroupQuorum::Any`]
+/// it is raised once any member is done. It is raised again if the group stops being ready
+/// (such as when a member starts more work, or a new member joins) and then becomes ready again.
+#[derive(Event)]
+pub struct AllDone<T: ?Sized> {
+    ready: usize,
+    members: usize,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> AllDone<T> {
+    fn new(ready: usize, members: usize) -> Self {
+        Self {
+            ready,
+            members,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the number of members that were done, and the total number of members.
+    #[inline]
+    pub fn members(&self) -> (usize, usize) {
+        (self.ready, self.members)
+    }
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, entity::EntityHashMap, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_hierarchy::prelude::*;
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems, ResetPolicy};
+
+/// Rolls up progress for `T` through the entity hierarchy.
+///
+/// Each tick, the [`Progress<T>`] of every descendant of an entity is added to the entity's own [`Progress<T>`].
+/// Entities without a tracker are passed through, so grandchildren still count toward their ancestors.
+/// As a result, a tracker only completes when everything below it has completed.
+///
+/// Every tracker in the hierarchy is still checked on its own, so events like [`Done`](crate::Done)
+/// are raised for each child as it completes, as well as for its ancestors.
+/// Observers only interested in the root can filter by [`Done::entity`](crate::Done::entity).
+///
+/// This requires [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin) to be added.
+/// With a [`ResetPolicy`] other than [`EveryTick`](ResetPolicy::EveryTick), only the change in
+/// descendants' work since the last tick is added to each tracker, so it isn't counted twice.
+pub struct HierarchicalProgressPlugin<T: ?Sized> {
+    /// The schedule in which progress is rolled up.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for HierarchicalProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for HierarchicalProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, hierarchy_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn hierarchy_progress_system<T: ?Sized + Send + Sync + 'static>(
+    roots: Query<(Entity, Option<&Parent>), With<Progress<T>>>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut trackers: Query<&mut Progress<T>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<EntityHashMap<SyncedWork>>,
+) {
+    let mut sync = SyncState {
+        policy: policies.and_then(|v| v.entities),
+        // Rebuilt each run, so entities that are no longer tracked are dropped
+        previous: std::mem::take(&mut *synced),
+        current: &mut synced,
+    };
+
+    for (entity, parent) in &roots {
+        // Skip any tracker that has a tracked ancestor, since it'll be visited from there.
+        if has_tracked_ancestor(parent.map(Parent::get), &parents, &trackers) { continue }
+        propagate(entity, &children, &mut trackers, &mut sync);
+    }
+}
+
+struct SyncState<'a> {
+    policy: Option<ResetPolicy>,
+    previous: EntityHashMap<SyncedWork>,
+    current: &'a mut EntityHashMap<SyncedWork>,
+}
+
+fn has_tracked_ancestor<T: ?Sized + Send + Sync + 'static>(
+    mut parent: Option<Entity>,
+    parents: &Query<&Parent>,
+    trackers: &Query<&mut Progress<T>>,
+) -> bool {
+    while let Some(entity) = parent {
+        if trackers.contains(entity) { return true }
+        parent = parents.get(entity).ok().map(Parent::get);
+    }
+
+    false
+}
+
+fn propagate<T: ?Sized + Send + Sync + 'static>(
+    entity: Entity,
+    children: &Query<&Children>,
+    trackers: &mut Query<&mut Progress<T>>,
+    sync: &mut SyncState,
+) -> Progress<T> {
+    let mut sum = Progress::new();
+
+    if let Ok(entity_children) = children.get(entity) {
+        for &child in entity_children {
+            sum += propagate(child, children, trackers, sync);
+        }
+    }
+
+    match trackers.get_mut(entity) {
+        Ok(mut tracker) => {
+            let mut synced = sync.previous.remove(&entity).unwrap_or_default();
+            record_full_state(sync.policy, &mut tracker, &mut synced, |progress| *progress += &sum);
+            sync.current.insert(entity, synced);
+            Progress::new() + &*tracker
+        },
+
+        Err(_) => sum,
+    }
+}
@@ -0,0 +1,138 @@
+use std::{any::TypeId, fmt::Write, net::SocketAddr};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::HashSet;
+use crate::{server, ProgressRegistry, ProgressSystems};
+
+/// Serves the state of every tracker in the [`ProgressRegistry`] as JSON over HTTP.
+///
+/// This lets orchestration tooling health-check long startup sequences on headless servers.
+/// Any request to the address is answered with a JSON object like the following,
+/// with status `200` once the app is ready, and `503` before then.
+///
+/// The app is ready once at least one tracker has completed the work recorded in it,
+/// and no tracker has incomplete work. Trackers that are empty, such as ones that are never
+/// used, don't hold it back, but the app isn't ready if every tracker is empty.
+/// A tracker that is reset after completing still counts as having completed.
+///
+/// ```json
+/// {"done":false,"trackers":[{"type":"my_game::Loading","fraction":0.5,"done":5,"total":10,"eta":2.5,"stage":"assets"}]}
+/// ```
+///
+/// Only trackers stored as resources are included.
+/// The ETA, in seconds, requires an [`EtaEstimator`](crate::EtaEstimator), and the stage requires
+/// [`ProgressStages`](crate::ProgressStages). Both are `null` when unavailable.
+///
+/// Requests are handled on a background thread, from a snapshot taken after
+/// [`ProgressSystems::Check`] in [`PostUpdate`], so serving requests never blocks the app.
+pub struct ProgressStatusPlugin {
+    /// The address to listen on.
+    /// Defaults to `127.0.0.1:9090`.
+    pub address: SocketAddr,
+}
+
+impl Default for ProgressStatusPlugin {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::from(([127, 0, 0, 1], 9090)),
+        }
+    }
+}
+
+impl ProgressStatusPlugin {
+    /// Listens on `address`.
+    pub fn with_address(mut self, address: impl Into<SocketAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+}
+
+impl Plugin for ProgressStatusPlugin {
+    fn build(&self, app: &mut App) {
+        let response = server::Response {
+            status: "503 Service Unavailable",
+            content_type: "application/json",
+            body: "{\"done\":false,\"trackers\":[]}".into(),
+        };
+
+        let response = match server::spawn("progress status endpoint", self.address, response) {
+            Some(v) => v,
+            None => return,
+        };
+
+        app.insert_resource(StatusResponse(response));
+        app.add_systems(PostUpdate, status_snapshot_system
+            .after(ProgressSystems::Check));
+    }
+}
+
+/// The latest status, shared with the thread serving requests.
+#[derive(Resource)]
+struct StatusResponse(server::SharedResponse);
+
+fn status_snapshot_system(
+    world: &World,
+    mut completed: Local<HashSet<TypeId>>,
+) {
+    let (registry, response) = match (world.get_resource::<ProgressRegistry>(), world.get_resource::<StatusResponse>()) {
+        (Some(registry), Some(response)) => (registry, response),
+        _ => return,
+    };
+
+    let mut incomplete = false;
+    let mut trackers = String::new();
+
+    for entry in registry.iter() {
+        let info = match entry.resource(world) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        if info.has_completed() { completed.insert(entry.type_id()); }
+        incomplete |= !info.is_done();
+
+        let (done, total) = info.work();
+        if !trackers.is_empty() { trackers.push(',') }
+
+        let _ = write!(trackers, "{{\"type\":\"{}\",\"fraction\":{},\"done\":{done},\"total\":{total},\"eta\":{},\"stage\":{}}}",
+            escape(entry.type_name()),
+            number(info.fract() as f64),
+            entry.estimated_remaining(world).map_or("null".into(), |v| number(v.as_secs_f64())),
+            entry.stage(world).map_or("null".into(), |v| format!("\"{}\"", escape(v))),
+        );
+    }
+
+    let ready = !incomplete && !completed.is_empty();
+    let mut response = response.0.lock().unwrap();
+
+    response.status = match ready {
+        true => "200 OK",
+        false => "503 Service Unavailable",
+    };
+
+    response.body = format!("{{\"done\":{ready},\"trackers\":[{trackers}]}}");
+}
+
+fn number(value: f64) -> String {
+    // JSON has no representation for NaN or infinity
+    match value.is_finite() {
+        true => value.to_string(),
+        false => "null".into(),
+    }
+}
+
+/// Escapes `value` for use inside a JSON string.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\u{0}'..='\u{1f}' => { let _ = write!(escaped, "\\u{:04x}", c as u32); },
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
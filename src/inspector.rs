@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_egui::{egui, EguiContext};
+use bevy_window::PrimaryWindow;
+use crate::{EtaEstimator, Progress, ProgressInfo, ProgressRegistry};
+
+/// Adds `T` to a debug window listing all progress trackers, drawn with [`bevy_egui`].
+///
+/// The window lists the [`Progress<T>`] resource and every entity tracking `T`, as read through the
+/// [`ProgressRegistry`],
+/// along with their work, rate of work (if an [`EtaEstimator<T>`] exists), and whether they're stalled.
+/// It can be shown and hidden with [`ProgressInspector::open`].
+pub struct ProgressInspectorPlugin<T: ?Sized> {
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressInspectorPlugin<T> {
+    fn default() -> Self {
+        Self {
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressInspectorPlugin<T> {
+    fn build(&self, app: &mut App) {
+        // The window is shared by all tracker types
+        if !app.world().contains_resource::<ProgressInspector>() {
+            app.init_resource::<ProgressInspector>();
+            app.add_systems(Update, inspector_window_system);
+        }
+
+        app.world_mut().resource_mut::<ProgressInspector>().trackers.push(InspectedTracker {
+            name: bevy_utils::get_short_name(std::any::type_name::<T>()),
+            rows: inspector_rows::<T>,
+        });
+    }
+}
+
+/// The state of the progress tracker debug window, added by [`ProgressInspectorPlugin`].
+#[derive(Resource)]
+pub struct ProgressInspector {
+    /// Whether the window is shown.
+    pub open: bool,
+
+    trackers: Vec<InspectedTracker>,
+}
+
+impl Default for ProgressInspector {
+    fn default() -> Self {
+        Self {
+            open: true,
+            trackers: Vec::new(),
+        }
+    }
+}
+
+struct InspectedTracker {
+    name: String,
+    rows: fn(&World) -> Vec<InspectorRow>,
+}
+
+struct InspectorRow {
+    label: String,
+    work: (u64, u64),
+    fract: f32,
+    rate: Option<f64>,
+    stalled: bool,
+}
+
+impl InspectorRow {
+    fn new(label: String, info: ProgressInfo, rate: Option<f64>) -> Self {
+        Self {
+            label,
+            work: info.work(),
+            fract: info.fract(),
+            rate,
+            stalled: info.stalled(),
+        }
+    }
+}
+
+fn inspector_rows<T: ?Sized + Send + Sync + 'static>(world: &World) -> Vec<InspectorRow> {
+    let mut rows = Vec::new();
+
+    if let Some(resource) = world.get_resource::<Progress<T>>() {
+        let rate = world.get_resource::<EtaEstimator<T>>().and_then(|v| v.rate());
+        rows.push(InspectorRow::new("Resource".into(), ProgressInfo::new(resource), rate));
+    }
+
+    // The registry queries tracker components directly, rather than scanning every entity
+    let entry = world.get_resource::<ProgressRegistry>().and_then(|v| v.get::<T>());
+    for (entity, info) in entry.map(|v| v.entities(world)).unwrap_or_default() {
+        rows.push(InspectorRow::new(format!("{entity}"), info, None));
+    }
+
+    rows
+}
+
+fn inspector_window_system(world: &mut World) {
+    world.resource_scope(|world, mut inspector: Mut<ProgressInspector>| {
+        if !inspector.open { return }
+
+        let trackers: Vec<_> = inspector.trackers.iter()
+            .map(|tracker| (tracker.name.as_str(), (tracker.rows)(world)))
+            .collect();
+
+        let mut query = world.query_filtered::<&mut EguiContext, With<PrimaryWindow>>();
+        let mut context = match query.get_single_mut(world) {
+            Ok(v) => v,
+            Err(_) => return,
+        };
+
+        let mut open = true;
+
+        egui::Window::new("Progress").open(&mut open).show(context.get_mut(), |ui| {
+            for (name, rows) in &trackers {
+                ui.collapsing(*name, |ui| {
+                    if rows.is_empty() {
+                        ui.label("No trackers");
+                    }
+
+                    for row in rows {
+                        ui.horizontal(|ui| {
+                            let (done, total) = row.work;
+                            ui.label(&row.label);
+                            ui.add(egui::ProgressBar::new(row.fract).text(format!("{done}/{total}")));
+
+                            if let Some(rate) = row.rate {
+                                ui.label(format!("{rate:.1}/s"));
+                            }
+
+                            if row.stalled {
+                                ui.colored_label(egui::Color32::YELLOW, "Stalled");
+                            }
+                        });
+                    }
+                });
+            }
+        });
+
+        inspector.open = open;
+    });
+}
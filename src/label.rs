@@ -0,0 +1,76 @@
+use std::{borrow::Cow, marker::PhantomData};
+use bevy_ecs::prelude::*;
+
+#[cfg(feature="bevy_asset")]
+use bevy_asset::UntypedHandle;
+
+/// Display information for a [`Progress<T>`](crate::Progress) tracker.
+///
+/// Insert this as a [`Resource`] alongside the [`Progress<T>`](crate::Progress) resource,
+/// or as a [`Component`] alongside [`Progress<T>`](crate::Progress) components.
+/// It's included in [`Started<T>`](crate::Started) and [`Done<T>`](crate::Done) events raised for the tracker.
+#[derive(Component, Resource)]
+pub struct ProgressLabel<T: ?Sized> {
+    name: Cow<'static, str>,
+    description: Option<Cow<'static, str>>,
+    #[cfg(feature="bevy_asset")]
+    icon: Option<UntypedHandle>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressLabel<T> {
+    /// Creates a new [`ProgressLabel`] with a human-readable name.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            #[cfg(feature="bevy_asset")]
+            icon: None,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Adds a human-readable description.
+    pub fn with_description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds an icon, such as a handle to an image.
+    #[cfg(feature="bevy_asset")]
+    pub fn with_icon(mut self, icon: impl Into<UntypedHandle>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Returns the name of the tracker.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of the tracker, if it has one.
+    #[inline]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the icon of the tracker, if it has one.
+    #[cfg(feature="bevy_asset")]
+    #[inline]
+    pub fn icon(&self) -> Option<&UntypedHandle> {
+        self.icon.as_ref()
+    }
+}
+
+impl<T: ?Sized> Clone for ProgressLabel<T> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            #[cfg(feature="bevy_asset")]
+            icon: self.icon.clone(),
+            _p1: PhantomData,
+        }
+    }
+}
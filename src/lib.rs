@@ -3,18 +3,495 @@
 #![warn(missing_docs)]
 
 use std::marker::PhantomData;
+use bevy_utils::{Duration, Instant, Parallel};
 use bevy_app::prelude::*;
-use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_ecs::{prelude::*, entity::EntityHashSet, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use attribution::AttributionState;
+use debounce::{DebounceConfig, DebounceState};
+use failure::FailureState;
+use placement::SystemPlacement;
+use stall::StallState;
+use storage::TrackerComponent;
+
+#[cfg(feature="bevy_reflect")]
+use bevy_reflect::{Reflect, std_traits::ReflectDefault};
+
+#[cfg(feature="bevy_reflect")]
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+
+#[cfg(feature="serde")]
+use serde::{Serialize, Deserialize};
+
+pub mod prelude;
+
+mod attribution;
+mod auto;
+mod bytes;
+mod cancel;
+mod channel;
+mod combine;
+mod commands;
+mod completed;
+mod condition;
+mod consolidated;
+mod debounce;
+mod despawn;
+mod display;
+mod dump;
+mod dynamic;
+mod eta;
+mod event;
+mod failure;
+mod forward;
+mod future;
+mod graph;
+mod group;
+mod label;
+mod log;
+mod manual;
+mod milestone;
+mod mirror;
+mod oneshot;
+mod ops;
+mod param;
+mod pipe;
+mod pipeline;
+mod placement;
+mod plugins;
+mod query;
+mod queue;
+mod registry;
+mod reset;
+mod retry;
+mod shared;
+mod skip;
+mod smooth;
+mod snapshot;
+mod stages;
+mod stall;
+mod storage;
+mod task;
+mod timeout;
+mod track;
+mod tracked;
+mod world;
+
+#[cfg(feature="bevy_asset")]
+mod asset;
+
+#[cfg(feature="bevy_diagnostic")]
+mod diagnostic;
+
+#[cfg(feature="bevy_egui")]
+mod inspector;
+
+#[cfg(feature="bevy_hierarchy")]
+mod hierarchy;
+
+#[cfg(feature="bevy_reflect")]
+mod reflect;
+
+#[cfg(feature="bevy_render")]
+mod extract;
+
+#[cfg(feature="bevy_render")]
+mod render;
+
+#[cfg(feature="bevy_scene")]
+mod scene;
+
+#[cfg(feature="bevy_state")]
+mod state;
+
+#[cfg(feature="bevy_text")]
+mod text;
+
+#[cfg(feature="bevy_time")]
+mod timed;
+
+#[cfg(feature="bevy_ui")]
+mod ui;
+
+#[cfg(feature="http")]
+mod http;
+
+#[cfg(any(feature="http", feature="prometheus"))]
+mod server;
+
+#[cfg(feature="indicatif")]
+mod terminal;
+
+#[cfg(feature="prometheus")]
+mod metrics;
+
+#[cfg(feature="replication")]
+mod replication;
+
+#[cfg(feature="replicon")]
+mod replicon;
+
+#[cfg(feature="tracing")]
+mod span;
+
+pub use auto::*;
+pub use bytes::*;
+pub use cancel::*;
+pub use channel::*;
+pub use combine::*;
+pub use commands::*;
+pub use completed::*;
+pub use condition::*;
+pub use debounce::*;
+pub use despawn::*;
+pub use dump::*;
+pub use dynamic::*;
+pub use eta::*;
+pub use event::*;
+pub use failure::*;
+pub use forward::*;
+pub use future::*;
+pub use graph::*;
+pub use group::*;
+pub use label::*;
+pub use log::*;
+pub use manual::*;
+pub use milestone::*;
+pub use mirror::*;
+pub use oneshot::*;
+pub use param::*;
+pub use pipe::*;
+pub use pipeline::*;
+pub use plugins::*;
+pub use query::*;
+pub use queue::*;
+pub use registry::*;
+pub use reset::*;
+pub use retry::*;
+pub use shared::*;
+pub use skip::*;
+pub use smooth::*;
+pub use snapshot::*;
+pub use stages::*;
+pub use stall::*;
+pub use storage::*;
+pub use task::*;
+pub use timeout::*;
+pub use track::*;
+pub use tracked::*;
+pub use world::*;
+
+#[cfg(feature="bevy_asset")]
+pub use asset::*;
+
+#[cfg(feature="bevy_diagnostic")]
+pub use diagnostic::*;
+
+#[cfg(feature="bevy_egui")]
+pub use inspector::*;
+
+#[cfg(feature="bevy_hierarchy")]
+pub use hierarchy::*;
+
+#[cfg(feature="bevy_render")]
+pub use extract::*;
+
+#[cfg(feature="bevy_render")]
+pub use render::*;
+
+#[cfg(feature="bevy_scene")]
+pub use scene::*;
+
+#[cfg(feature="bevy_state")]
+pub use state::*;
+
+#[cfg(feature="bevy_text")]
+pub use text::*;
+
+#[cfg(feature="bevy_time")]
+pub use timed::*;
+
+#[cfg(feature="bevy_ui")]
+pub use ui::*;
+
+/// Adds convenience constructors to a marker type, cutting down on type parameters.
+///
+/// ```ignore
+/// #[derive(ProgressMarker)]
+/// enum Loading {}
+///
+/// app.add_plugins(Loading::plugin());
+/// app.add_systems(Update, start_game.run_if(Loading::done_condition()));
+/// ```
+///
+/// The following associated functions are generated:
+/// - `plugin()`, returning a [`ResourceProgressTrackingPlugin`]
+/// - `entity_plugin()`, returning an [`EntityProgressTrackingPlugin`]
+/// - `done_condition()`, returning [`progress_done`]
+/// - `not_done_condition()`, returning [`progress_not_done`]
+#[cfg(feature="derive")]
+pub use bevy_mod_progress_macros::ProgressMarker;
+
+#[doc(hidden)]
+#[cfg(feature="derive")]
+pub mod __macro_exports {
+    pub use bevy_ecs::system::Res;
+}
+
+#[cfg(feature="http")]
+pub use http::*;
+
+#[cfg(feature="indicatif")]
+pub use terminal::*;
+
+#[cfg(feature="prometheus")]
+pub use metrics::*;
+
+#[cfg(feature="replication")]
+pub use replication::*;
+
+#[cfg(feature="replicon")]
+pub use replicon::*;
+
+/// Adds progress tracking for `T`, both as a resource and as a component.
+///
+/// This is a combination of [`ResourceProgressTrackingPlugin`] and [`EntityProgressTrackingPlugin`].
+/// Either side can be disabled with [`without_resource`](Self::without_resource)
+/// or [`without_entities`](Self::without_entities).
+///
+/// # Fixed timestep
+/// By default, trackers are checked in [`PostUpdate`] and reset in [`Last`], once per frame.
+/// Progress recorded in [`FixedUpdate`] should instead use [`in_fixed_timestep`](Self::in_fixed_timestep),
+/// which checks in [`FixedPostUpdate`] and resets in [`FixedLast`].
+///
+/// Since these schedules run in order for every fixed step, work recorded in [`FixedUpdate`]
+/// is always checked before it's reset, no matter how many fixed steps run in a frame.
+/// Frames with no fixed steps don't check or reset at all, so work recorded elsewhere
+/// (such as in [`Update`]) carries over to the next fixed step rather than being lost.
+/// Plugins that feed or read trackers, such as [`ProgressTaskPlugin`], should be moved
+/// to [`FixedPostUpdate`] using their `schedule` field.
+pub struct ProgressTrackingPlugin<T: ?Sized> {
+    /// The schedule in which the progress value is checked.
+    pub check_schedule: InternedScheduleLabel,
+
+    /// The schedule in which the progress value is reset.
+    /// This should be the same as, or after, `check_schedule`.
+    pub reset_schedule: InternedScheduleLabel,
+
+    /// Whether to track progress for `T` as a resource.
+    pub resource: bool,
+
+    /// Whether to track progress for `T` as a component.
+    pub entities: bool,
+
+    /// Whether to also write [`Done<T>`] into an [`Events`] buffer, for use with [`EventReader`].
+    pub buffered_events: bool,
+
+    /// When trackers are reset.
+    pub reset_policy: ResetPolicy,
+
+    /// Whether to capture a [`ProgressSnapshot<T>`] of trackers right before they are reset.
+    pub snapshot_before_reset: bool,
+
+    /// Whether to mark entities with [`Completed<T>`] when their tracker finishes.
+    pub completed_marker: CompletedMarker,
+
+    /// Conditions trackers must meet before [`Done<T>`] is raised.
+    pub debounce: CompletionDebounce,
+
+    /// Whether to run the plugin's systems from shared systems, instead of adding them to the schedule.
+    /// See [`consolidated`](Self::consolidated) for details.
+    pub consolidated: bool,
+
+    /// How trackers on entities are stored.
+    pub storage: ProgressStorage,
+
+    placement: SystemPlacement,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressTrackingPlugin<T> {
+    fn default() -> Self {
+        Self {
+            check_schedule: PostUpdate.intern(),
+            reset_schedule: Last.intern(),
+            resource: true,
+            entities: true,
+            buffered_events: false,
+            reset_policy: ResetPolicy::EveryTick,
+            snapshot_before_reset: false,
+            completed_marker: CompletedMarker::Disabled,
+            debounce: CompletionDebounce::new(),
+            consolidated: false,
+            storage: ProgressStorage::Table,
+            placement: SystemPlacement::default(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> ProgressTrackingPlugin<T> {
+    /// Disables tracking progress for `T` as a resource.
+    pub fn without_resource(mut self) -> Self {
+        self.resource = false;
+        self
+    }
+
+    /// Disables tracking progress for `T` as a component.
+    pub fn without_entities(mut self) -> Self {
+        self.entities = false;
+        self
+    }
+
+    /// Enables writing [`Done<T>`] into an [`Events`] buffer, in addition to triggering it.
+    pub fn with_buffered_events(mut self) -> Self {
+        self.buffered_events = true;
+        self
+    }
+
+    /// Sets when trackers are reset.
+    pub fn with_reset_policy(mut self, policy: ResetPolicy) -> Self {
+        self.reset_policy = policy;
+        self
+    }
+
+    /// Enables capturing a [`ProgressSnapshot<T>`] of trackers right before they are reset.
+    pub fn with_snapshots(mut self) -> Self {
+        self.snapshot_before_reset = true;
+        self
+    }
+
+    /// Sets whether entities are marked with [`Completed<T>`] when their tracker finishes.
+    pub fn with_completed_marker(mut self, marker: CompletedMarker) -> Self {
+        self.completed_marker = marker;
+        self
+    }
+
+    /// Checks trackers in [`FixedPostUpdate`] and resets them in [`FixedLast`],
+    /// for progress recorded in [`FixedUpdate`].
+    ///
+    /// See [`ProgressTrackingPlugin`] for details.
+    pub fn in_fixed_timestep(mut self) -> Self {
+        self.check_schedule = FixedPostUpdate.intern();
+        self.reset_schedule = FixedLast.intern();
+        self
+    }
+
+    /// Sets conditions trackers must meet before [`Done<T>`] is raised.
+    pub fn with_debounce(mut self, debounce: CompletionDebounce) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Runs the plugin's systems from a single system shared by all types added this way,
+    /// instead of adding separate systems to the schedule for each type.
+    ///
+    /// This keeps the schedule small in apps with many tracker types, at the cost of parallelism.
+    /// Conditions added with [`run_if`](Self::run_if) still apply, but sets and ordering
+    /// from [`in_set`](Self::in_set), [`before`](Self::before), and [`after`](Self::after) do not.
+    pub fn consolidated(mut self) -> Self {
+        self.consolidated = true;
+        self
+    }
+
+    /// Sets how trackers on entities are stored.
+    ///
+    /// With [`ProgressStorage::SparseSet`], trackers are [`SparseProgress<T>`] components.
+    /// See its documentation for the trade-offs.
+    pub fn with_storage(mut self, storage: ProgressStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Only runs the systems added by the plugin if `condition` is met.
+    ///
+    /// This can be called more than once, in which case all conditions must be met.
+    pub fn run_if<M>(mut self, condition: impl Condition<M> + Clone + Send + Sync + 'static) -> Self {
+        self.placement.run_if(condition);
+        self
+    }
+
+    /// Adds the systems added by the plugin to `set`, in addition to [`ProgressSystems`].
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.placement.in_set(set);
+        self
+    }
+
+    /// Checks trackers before `set` runs.
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.placement.before(set);
+        self
+    }
+
+    /// Checks trackers after `set` runs.
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.placement.after(set);
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressTrackingPlugin<T> {
+    fn build(&self, app: &mut App) {
+        if self.resource {
+            app.add_plugins(ResourceProgressTrackingPlugin::<T> {
+                check_schedule: self.check_schedule,
+                reset_schedule: self.reset_schedule,
+                buffered_events: self.buffered_events,
+                reset_policy: self.reset_policy,
+                snapshot_before_reset: self.snapshot_before_reset,
+                debounce: self.debounce,
+                consolidated: self.consolidated,
+                placement: self.placement.clone(),
+                _p1: PhantomData,
+            });
+        }
+
+        if self.entities {
+            app.add_plugins(EntityProgressTrackingPlugin::<T> {
+                check_schedule: self.check_schedule,
+                reset_schedule: self.reset_schedule,
+                buffered_events: self.buffered_events,
+                reset_policy: self.reset_policy,
+                snapshot_before_reset: self.snapshot_before_reset,
+                completed_marker: self.completed_marker,
+                debounce: self.debounce,
+                consolidated: self.consolidated,
+                storage: self.storage,
+                placement: self.placement.clone(),
+                _p1: PhantomData,
+            });
+        }
+    }
+}
 
 /// Adds progress tracking for `T` (as a resource).
+///
+/// Panics if tracking for `T` as a resource is added more than once,
+/// including through [`ProgressTrackingPlugin<T>`] or [`AppProgressExt`].
 pub struct ResourceProgressTrackingPlugin<T: ?Sized> {
     /// The schedule in which the progress value is checked.
     pub check_schedule: InternedScheduleLabel,
 
-    /// The schedule in which the progress value is checked.
-    /// This should be the same as, or before, `check_schedule`.
+    /// The schedule in which the progress value is reset.
+    /// This should be the same as, or after, `check_schedule`.
     pub reset_schedule: InternedScheduleLabel,
 
+    /// Whether to also write [`Done<T>`] into an [`Events`] buffer, for use with [`EventReader`].
+    pub buffered_events: bool,
+
+    /// When trackers are reset.
+    pub reset_policy: ResetPolicy,
+
+    /// Whether to capture a [`ProgressSnapshot<T>`] of trackers right before they are reset.
+    pub snapshot_before_reset: bool,
+
+    /// Conditions trackers must meet before [`Done<T>`] is raised.
+    pub debounce: CompletionDebounce,
+
+    /// Whether to run the plugin's systems from shared systems, instead of adding them to the schedule.
+    /// See [`consolidated`](Self::consolidated) for details.
+    pub consolidated: bool,
+
+    placement: SystemPlacement,
     _p1: PhantomData<T>,
 }
 
@@ -23,56 +500,250 @@ impl<T: ?Sized> Default for ResourceProgressTrackingPlugin<T> {
         Self {
             check_schedule: PostUpdate.intern(),
             reset_schedule: Last.intern(),
+            buffered_events: false,
+            reset_policy: ResetPolicy::EveryTick,
+            snapshot_before_reset: false,
+            debounce: CompletionDebounce::new(),
+            consolidated: false,
+            placement: SystemPlacement::default(),
             _p1: PhantomData,
         }
     }
 }
 
+impl<T: ?Sized> ResourceProgressTrackingPlugin<T> {
+    /// Checks trackers in [`FixedPostUpdate`] and resets them in [`FixedLast`],
+    /// for progress recorded in [`FixedUpdate`].
+    ///
+    /// See [`ProgressTrackingPlugin`] for details.
+    pub fn in_fixed_timestep(mut self) -> Self {
+        self.check_schedule = FixedPostUpdate.intern();
+        self.reset_schedule = FixedLast.intern();
+        self
+    }
+
+    /// Sets conditions trackers must meet before [`Done<T>`] is raised.
+    pub fn with_debounce(mut self, debounce: CompletionDebounce) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Runs the plugin's systems from a single system shared by all types added this way,
+    /// instead of adding separate systems to the schedule for each type.
+    ///
+    /// This keeps the schedule small in apps with many tracker types, at the cost of parallelism.
+    /// Conditions added with [`run_if`](Self::run_if) still apply, but sets and ordering
+    /// from [`in_set`](Self::in_set), [`before`](Self::before), and [`after`](Self::after) do not.
+    pub fn consolidated(mut self) -> Self {
+        self.consolidated = true;
+        self
+    }
+
+    /// Only runs the systems added by the plugin if `condition` is met.
+    ///
+    /// This can be called more than once, in which case all conditions must be met.
+    pub fn run_if<M>(mut self, condition: impl Condition<M> + Clone + Send + Sync + 'static) -> Self {
+        self.placement.run_if(condition);
+        self
+    }
+
+    /// Adds the systems added by the plugin to `set`, in addition to [`ProgressSystems`].
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.placement.in_set(set);
+        self
+    }
+
+    /// Checks trackers before `set` runs.
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.placement.before(set);
+        self
+    }
+
+    /// Checks trackers after `set` runs.
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.placement.after(set);
+        self
+    }
+}
+
 impl<T: Send + Sync + 'static> Plugin for ResourceProgressTrackingPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.add_systems(self.check_schedule, resource_progress_check_system::<T>
-            .in_set(ProgressSystems::Check));
+        self.build_with(app, || true);
+    }
+}
+
+impl<T: Send + Sync + 'static> ResourceProgressTrackingPlugin<T> {
+    /// Builds the plugin, only running the tracking systems if `condition` is met.
+    pub(crate) fn build_with<M>(&self, app: &mut App, condition: impl Condition<M> + Clone) {
+        if let Err(err) = registry::register::<T>(app, true) {
+            panic!("{err}");
+        }
+        app.world_mut().get_resource_or_insert_with(reset::ResetPolicies::<T>::default).resource = Some(self.reset_policy);
+
+        if self.debounce != CompletionDebounce::new() {
+            app.world_mut().get_resource_or_insert_with(DebounceConfig::<T>::default).resource = self.debounce;
+        }
+
+        if self.buffered_events {
+            app.add_event::<Done<T>>();
+        }
+
+        #[cfg(feature="bevy_reflect")]
+        reflect::register_types::<T>(app);
+
+        app.observe(cancel::resource_cancel_observer::<T>);
+        app.observe(reset::resource_reset_observer::<T>);
+        app.observe(track::resource_track_observer::<T>);
+
+        #[cfg(feature="tracing")]
+        span::setup::<T>(app);
+
+        app.init_resource::<track::PendingWork<T>>();
+
+        if self.consolidated {
+            consolidated::add_system(app, self.check_schedule, ProgressSystems::Check,
+                Vec::new(), track::resource_pending_system::<T>);
+
+            let mut conditions = consolidated::conditions::<T, M>(&self.placement, condition.clone());
+            conditions.push(consolidated::boxed(resource_progress_changed::<T>));
+            consolidated::add_system(app, self.check_schedule, ProgressSystems::Check,
+                conditions, resource_check_system::<T>);
 
-        app.add_systems(self.reset_schedule, resource_progress_reset_system::<T>
+            if self.snapshot_before_reset {
+                consolidated::add_system(app, self.reset_schedule, ProgressSystems::Reset,
+                    consolidated::conditions::<T, M>(&self.placement, condition.clone()),
+                    snapshot::resource_snapshot_system::<T>(self.reset_policy));
+            }
+
+            consolidated::add_system(app, self.reset_schedule, ProgressSystems::Reset,
+                consolidated::conditions::<T, M>(&self.placement, condition),
+                resource_reset_system::<T>(self.reset_policy));
+
+            return;
+        }
+
+        app.add_systems(self.check_schedule, track::resource_pending_system::<T>
+            .before(ProgressSystems::Check));
+
+        let check = resource_check_system::<T>
+            .in_set(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>)
+            .run_if(resource_progress_changed::<T>)
+            .run_if(condition.clone());
+
+        app.add_systems(self.check_schedule, self.placement.apply_ordered(check));
+
+        let reset = match self.snapshot_before_reset {
+            true => (snapshot::resource_snapshot_system::<T>(self.reset_policy), resource_reset_system::<T>(self.reset_policy)).chain(),
+            false => resource_reset_system::<T>(self.reset_policy).into_configs(),
+        };
+
+        let reset = reset
             .in_set(ProgressSystems::Reset)
-            .after(ProgressSystems::Check));
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>)
+            .run_if(condition);
+
+        app.add_systems(self.reset_schedule, self.placement.apply(reset));
     }
 }
 
-fn resource_progress_check_system<T: ?Sized + Send + Sync + 'static>(
-    mut commands: Commands,
+// Idle trackers are skipped, so that they cost next to nothing.
+fn resource_progress_changed<T: ?Sized + Send + Sync + 'static>(
     resource: Option<Res<Progress<T>>>,
+) -> bool {
+    resource.is_some_and(|v| v.is_changed() || v.checks_unchanged())
+}
+
+fn resource_check_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Option<ResMut<Progress<T>>>,
+    label: Option<Res<ProgressLabel<T>>>,
+    events: Option<ResMut<Events<Done<T>>>>,
+    debounce: Option<Res<DebounceConfig<T>>>,
 ) {
-    let resource = match resource {
+    let mut resource = match resource {
         Some(v) => v,
         None => return,
     };
 
-    if !resource.done() { return }
-    commands.trigger(Done::<T> {
-        work: resource.total,
-        _p1: PhantomData,
-    });
+    let debounce = debounce.map(|v| v.resource).unwrap_or_default();
+    let checked = resource.bypass_change_detection().check_with(&debounce);
+    let label = label.as_deref();
+
+    if checked.started {
+        commands.trigger(Started::<T>::new(&resource, label, None));
+    }
+
+    if checked.updated {
+        commands.trigger(Updated::<T>::new(&resource, checked.previous, None));
+    }
+
+    if let Some((frames, duration)) = checked.stalled {
+        commands.trigger(Stalled::<T>::new(frames, duration, None));
+    }
+
+    if checked.failed {
+        commands.trigger(Failed::<T>::new(&resource, None));
+    }
+
+    if checked.done {
+        commands.trigger(Done::<T>::new(&resource, label, None));
+
+        if let Some(mut events) = events {
+            events.send(Done::<T>::new(&resource, label, None));
+        }
+    }
 }
 
-fn resource_progress_reset_system<T: ?Sized + Send + Sync + 'static>(
-    resource: Option<ResMut<Progress<T>>>,
-) {
-    if let Some(mut resource) = resource {
-        resource.done = 0;
-        resource.total = 0;
+fn resource_reset_system<T: ?Sized + Send + Sync + 'static>(
+    policy: ResetPolicy,
+) -> impl FnMut(Option<ResMut<Progress<T>>>) {
+    move |resource| {
+        if let Some(mut resource) = resource {
+            if !resource.needs_reset(policy) { return }
+            resource.reset_with(policy);
+        }
     }
 }
 
 /// Adds progress tracking for `T` (as a component).
+///
+/// Panics if tracking for `T` as a component is added more than once,
+/// including through [`ProgressTrackingPlugin<T>`] or [`AppProgressExt`].
 pub struct EntityProgressTrackingPlugin<T: ?Sized> {
     /// The schedule in which the progress value is checked.
     pub check_schedule: InternedScheduleLabel,
 
-    /// The schedule in which the progress value is checked.
-    /// This should be the same as, or before, `check_schedule`.
+    /// The schedule in which the progress value is reset.
+    /// This should be the same as, or after, `check_schedule`.
     pub reset_schedule: InternedScheduleLabel,
 
+    /// Whether to also write [`Done<T>`] into an [`Events`] buffer, for use with [`EventReader`].
+    pub buffered_events: bool,
+
+    /// When trackers are reset.
+    pub reset_policy: ResetPolicy,
+
+    /// Whether to capture a [`ProgressSnapshot<T>`] of trackers right before they are reset.
+    pub snapshot_before_reset: bool,
+
+    /// Whether to mark entities with [`Completed<T>`] when their tracker finishes.
+    pub completed_marker: CompletedMarker,
+
+    /// Conditions trackers must meet before [`Done<T>`] is raised.
+    pub debounce: CompletionDebounce,
+
+    /// Whether to run the plugin's systems from shared systems, instead of adding them to the schedule.
+    /// See [`consolidated`](Self::consolidated) for details.
+    pub consolidated: bool,
+
+    /// How trackers on entities are stored.
+    pub storage: ProgressStorage,
+
+    placement: SystemPlacement,
     _p1: PhantomData<T>,
 }
 
@@ -81,64 +752,588 @@ impl<T: ?Sized> Default for EntityProgressTrackingPlugin<T> {
         Self {
             check_schedule: PostUpdate.intern(),
             reset_schedule: Last.intern(),
+            buffered_events: false,
+            reset_policy: ResetPolicy::EveryTick,
+            snapshot_before_reset: false,
+            completed_marker: CompletedMarker::Disabled,
+            debounce: CompletionDebounce::new(),
+            consolidated: false,
+            storage: ProgressStorage::Table,
+            placement: SystemPlacement::default(),
             _p1: PhantomData,
         }
     }
 }
 
+impl<T: ?Sized> EntityProgressTrackingPlugin<T> {
+    /// Checks trackers in [`FixedPostUpdate`] and resets them in [`FixedLast`],
+    /// for progress recorded in [`FixedUpdate`].
+    ///
+    /// See [`ProgressTrackingPlugin`] for details.
+    pub fn in_fixed_timestep(mut self) -> Self {
+        self.check_schedule = FixedPostUpdate.intern();
+        self.reset_schedule = FixedLast.intern();
+        self
+    }
+
+    /// Sets conditions trackers must meet before [`Done<T>`] is raised.
+    pub fn with_debounce(mut self, debounce: CompletionDebounce) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Runs the plugin's systems from a single system shared by all types added this way,
+    /// instead of adding separate systems to the schedule for each type.
+    ///
+    /// This keeps the schedule small in apps with many tracker types, at the cost of parallelism.
+    /// Conditions added with [`run_if`](Self::run_if) still apply, but sets and ordering
+    /// from [`in_set`](Self::in_set), [`before`](Self::before), and [`after`](Self::after) do not.
+    pub fn consolidated(mut self) -> Self {
+        self.consolidated = true;
+        self
+    }
+
+    /// Sets how trackers on entities are stored.
+    ///
+    /// With [`ProgressStorage::SparseSet`], trackers are [`SparseProgress<T>`] components.
+    /// See its documentation for the trade-offs.
+    pub fn with_storage(mut self, storage: ProgressStorage) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Only runs the systems added by the plugin if `condition` is met.
+    ///
+    /// This can be called more than once, in which case all conditions must be met.
+    pub fn run_if<M>(mut self, condition: impl Condition<M> + Clone + Send + Sync + 'static) -> Self {
+        self.placement.run_if(condition);
+        self
+    }
+
+    /// Adds the systems added by the plugin to `set`, in addition to [`ProgressSystems`].
+    pub fn in_set(mut self, set: impl SystemSet) -> Self {
+        self.placement.in_set(set);
+        self
+    }
+
+    /// Checks trackers before `set` runs.
+    pub fn before<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.placement.before(set);
+        self
+    }
+
+    /// Checks trackers after `set` runs.
+    pub fn after<M>(mut self, set: impl IntoSystemSet<M>) -> Self {
+        self.placement.after(set);
+        self
+    }
+}
+
 impl<T: Send + Sync + 'static> Plugin for EntityProgressTrackingPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.add_systems(self.check_schedule, entity_progress_check_system::<T>
-            .in_set(ProgressSystems::Check));
+        self.build_with(app, || true);
+    }
+}
+
+impl<T: Send + Sync + 'static> EntityProgressTrackingPlugin<T> {
+    /// Builds the plugin, only running the tracking systems if `condition` is met.
+    pub(crate) fn build_with<M>(&self, app: &mut App, condition: impl Condition<M> + Clone) {
+        if let Err(err) = registry::register::<T>(app, false) {
+            panic!("{err}");
+        }
+        app.world_mut().get_resource_or_insert_with(reset::ResetPolicies::<T>::default).entities = Some(self.reset_policy);
+
+        if self.debounce != CompletionDebounce::new() {
+            app.world_mut().get_resource_or_insert_with(DebounceConfig::<T>::default).entities = self.debounce;
+        }
+
+        if self.buffered_events {
+            app.add_event::<Done<T>>();
+        }
+
+        #[cfg(feature="bevy_reflect")]
+        reflect::register_types::<T>(app);
+
+        app.observe(track::entity_track_observer::<T>);
+        app.observe(despawn::despawn_on_done_observer::<T>);
+
+        #[cfg(feature="tracing")]
+        span::setup::<T>(app);
+
+        if self.completed_marker != CompletedMarker::Disabled {
+            app.observe(completed::completed_insert_observer::<T>(self.completed_marker));
+        }
+
+        if self.completed_marker == CompletedMarker::Insert {
+            app.observe(completed::completed_remove_observer::<T>);
+        }
+
+        app.init_resource::<track::PendingWork<T>>();
+
+        match self.storage {
+            ProgressStorage::Table => self.build_storage::<Progress<T>, M>(app, condition),
+            ProgressStorage::SparseSet => self.build_storage::<SparseProgress<T>, M>(app, condition),
+        }
+    }
+
+    /// Adds the systems and observers that access trackers, stored as `C`.
+    fn build_storage<C: TrackerComponent<T>, M>(&self, app: &mut App, condition: impl Condition<M> + Clone) {
+        app.observe(cancel::entity_cancel_observer::<T, C>);
+        app.observe(reset::entity_reset_observer::<T, C>);
+
+        if self.consolidated {
+            consolidated::add_system(app, self.check_schedule, ProgressSystems::Check,
+                Vec::new(), track::entity_pending_system::<T, C>);
+
+            consolidated::add_system(app, self.check_schedule, ProgressSystems::Check,
+                consolidated::conditions::<T, M>(&self.placement, condition.clone()),
+                entity_check_system::<T, C>);
+
+            if self.snapshot_before_reset {
+                consolidated::add_system(app, self.reset_schedule, ProgressSystems::Reset,
+                    consolidated::conditions::<T, M>(&self.placement, condition.clone()),
+                    snapshot::entity_snapshot_system::<T, C>(self.reset_policy));
+            }
 
-        app.add_systems(self.reset_schedule, entity_progress_reset_system::<T>
+            consolidated::add_system(app, self.reset_schedule, ProgressSystems::Reset,
+                consolidated::conditions::<T, M>(&self.placement, condition),
+                entity_reset_system::<T, C>(self.reset_policy));
+
+            return;
+        }
+
+        app.add_systems(self.check_schedule, track::entity_pending_system::<T, C>
+            .before(ProgressSystems::Check));
+
+        let check = entity_check_system::<T, C>
+            .in_set(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>)
+            .run_if(condition.clone());
+
+        app.add_systems(self.check_schedule, self.placement.apply_ordered(check));
+
+        let reset = match self.snapshot_before_reset {
+            true => (snapshot::entity_snapshot_system::<T, C>(self.reset_policy), entity_reset_system::<T, C>(self.reset_policy)).chain(),
+            false => entity_reset_system::<T, C>(self.reset_policy).into_configs(),
+        };
+
+        let reset = reset
             .in_set(ProgressSystems::Reset)
-            .after(ProgressSystems::Check));
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>)
+            .run_if(condition);
+
+        app.add_systems(self.reset_schedule, self.placement.apply(reset));
     }
 }
 
-fn entity_progress_check_system<T: ?Sized + Send + Sync + 'static>(
+/// Trackers that changed since the last check, and all trackers.
+type EntityTrackers<'w, 's, C> = ParamSet<'w, 's, (
+    Query<'static, 'static, (Entity, &'static mut C), Changed<C>>,
+    Query<'static, 'static, &'static mut C>,
+)>;
+
+fn entity_check_system<T: ?Sized + Send + Sync + 'static, C: TrackerComponent<T>>(
     mut commands: Commands,
-    query: Query<(Entity, &Progress<T>)>,
+    mut trackers: EntityTrackers<C>,
+    labels: Query<&ProgressLabel<T>>,
+    events: Option<ResMut<Events<Done<T>>>>,
+    debounce: Option<Res<DebounceConfig<T>>>,
+    mut buffer: Local<Parallel<Vec<CheckedEntity<T>>>>,
+    mut watched: Local<WatchedTrackers>,
 ) {
-    for (entity, tracker) in &query {
-        if !tracker.done() { continue }
-        commands.trigger_targets(Done::<T> {
-            work: tracker.total,
-            _p1: PhantomData,
-        }, [entity]);
+    let buffered = events.is_some();
+    let debounce = debounce.map(|v| v.entities).unwrap_or_default();
+    let watched = &mut *watched;
+
+    // Idle trackers are filtered out, so that they cost next to nothing.
+    trackers.p0().par_iter_mut().for_each(|(entity, mut tracker)| {
+        let tracker = tracker.bypass_change_detection().tracker_mut();
+        let result = tracker.check_with(&debounce);
+        watched.changed.borrow_local_mut().push((entity, tracker.checks_unchanged()));
+        if !result.any() { return }
+
+        let label = labels.get(entity).ok();
+        buffer.borrow_local_mut().push(CheckedEntity::new(tracker, label, entity, result, buffered));
+    });
+
+    // Some trackers can raise events without changing, such as when stalled or debounced,
+    // so they're remembered and checked separately, as the filter skips them.
+    let mut query = trackers.p1();
+    let mut unwatched = Vec::new();
+
+    for &entity in &watched.entities {
+        let mut tracker = match query.get_mut(entity) {
+            Ok(v) => v,
+            Err(_) => { unwatched.push(entity); continue },
+        };
+
+        // Already checked above
+        if tracker.is_changed() { continue }
+
+        let tracker = tracker.bypass_change_detection().tracker_mut();
+        let result = tracker.check_with(&debounce);
+        if !tracker.checks_unchanged() { unwatched.push(entity) }
+        if !result.any() { continue }
+
+        let label = labels.get(entity).ok();
+        buffer.borrow_local_mut().push(CheckedEntity::new(tracker, label, entity, result, buffered));
+    }
+
+    for entity in unwatched {
+        watched.entities.remove(&entity);
     }
+
+    for (entity, watch) in watched.changed.iter_mut().flat_map(|v| v.drain(..)) {
+        match watch {
+            true => watched.entities.insert(entity),
+            false => watched.entities.remove(&entity),
+        };
+    }
+
+    let mut checked = Vec::new();
+    buffer.drain_into(&mut checked);
+    if checked.is_empty() { return }
+
+    if let Some(mut events) = events {
+        events.send_batch(checked.iter_mut().filter_map(|v| v.buffered.take()));
+    }
+
+    // Triggered from a single command, rather than queueing a command for every event.
+    commands.add(move |world: &mut World| {
+        for checked in checked {
+            checked.trigger(world);
+        }
+    });
 }
 
-fn entity_progress_reset_system<T: ?Sized + Send + Sync + 'static>(
-    mut query: Query<&mut Progress<T>>,
-) {
-    for mut tracker in &mut query {
-        tracker.done = 0;
-        tracker.total = 0;
+/// Entities whose trackers are checked even when they haven't changed.
+#[derive(Default)]
+struct WatchedTrackers {
+    entities: EntityHashSet,
+    changed: Parallel<Vec<(Entity, bool)>>,
+}
+
+/// The events to raise for an entity after checking its tracker.
+struct CheckedEntity<T: ?Sized> {
+    entity: Entity,
+    started: Option<Started<T>>,
+    updated: Option<Updated<T>>,
+    stalled: Option<Stalled<T>>,
+    failed: Option<Failed<T>>,
+    done: Option<Done<T>>,
+    buffered: Option<Done<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> CheckedEntity<T> {
+    fn new(
+        progress: &Progress<T>,
+        label: Option<&ProgressLabel<T>>,
+        entity: Entity,
+        checked: Checked,
+        buffered: bool,
+    ) -> Self {
+        Self {
+            entity,
+            started: checked.started.then(|| Started::<T>::new(progress, label, Some(entity))),
+            updated: checked.updated.then(|| Updated::<T>::new(progress, checked.previous, Some(entity))),
+            stalled: checked.stalled.map(|(frames, duration)| Stalled::<T>::new(frames, duration, Some(entity))),
+            failed: checked.failed.then(|| Failed::<T>::new(progress, Some(entity))),
+            done: checked.done.then(|| Done::<T>::new(progress, label, Some(entity))),
+            buffered: (checked.done && buffered).then(|| Done::<T>::new(progress, label, Some(entity))),
+        }
+    }
+
+    fn trigger(self, world: &mut World) {
+        let entity = self.entity;
+        if let Some(event) = self.started { world.trigger_targets(event, entity) }
+        if let Some(event) = self.updated { world.trigger_targets(event, entity) }
+        if let Some(event) = self.stalled { world.trigger_targets(event, entity) }
+        if let Some(event) = self.failed { world.trigger_targets(event, entity) }
+        if let Some(event) = self.done { world.trigger_targets(event, entity) }
+    }
+}
+
+fn entity_reset_system<T: ?Sized + Send + Sync + 'static, C: TrackerComponent<T>>(
+    policy: ResetPolicy,
+) -> impl FnMut(Query<&mut C>) {
+    move |mut query| {
+        for mut tracker in &mut query {
+            if !tracker.tracker().needs_reset(policy) { continue }
+            tracker.tracker_mut().reset_with(policy);
+        }
+    }
+}
+
+/// Extension trait for registering progress trackers on an [`App`].
+pub trait AppProgressExt {
+    /// Adds progress tracking for `T` as a resource, using the default schedules.
+    fn track_resource_progress<T: Send + Sync + 'static>(&mut self) -> &mut Self;
+
+    /// Adds progress tracking for `T` as a resource, checking in `check_schedule` and resetting in `reset_schedule`.
+    fn track_resource_progress_in<T: Send + Sync + 'static>(
+        &mut self,
+        check_schedule: impl ScheduleLabel,
+        reset_schedule: impl ScheduleLabel,
+    ) -> &mut Self;
+
+    /// Adds progress tracking for `T` as a component, using the default schedules.
+    fn track_entity_progress<T: Send + Sync + 'static>(&mut self) -> &mut Self;
+
+    /// Adds progress tracking for `T` as a component, checking in `check_schedule` and resetting in `reset_schedule`.
+    fn track_entity_progress_in<T: Send + Sync + 'static>(
+        &mut self,
+        check_schedule: impl ScheduleLabel,
+        reset_schedule: impl ScheduleLabel,
+    ) -> &mut Self;
+}
+
+impl AppProgressExt for App {
+    fn track_resource_progress<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.add_plugins(ResourceProgressTrackingPlugin::<T>::default())
+    }
+
+    fn track_resource_progress_in<T: Send + Sync + 'static>(
+        &mut self,
+        check_schedule: impl ScheduleLabel,
+        reset_schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.add_plugins(ResourceProgressTrackingPlugin::<T> {
+            check_schedule: check_schedule.intern(),
+            reset_schedule: reset_schedule.intern(),
+            ..Default::default()
+        })
+    }
+
+    fn track_entity_progress<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.add_plugins(EntityProgressTrackingPlugin::<T>::default())
+    }
+
+    fn track_entity_progress_in<T: Send + Sync + 'static>(
+        &mut self,
+        check_schedule: impl ScheduleLabel,
+        reset_schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.add_plugins(EntityProgressTrackingPlugin::<T> {
+            check_schedule: check_schedule.intern(),
+            reset_schedule: reset_schedule.intern(),
+            ..Default::default()
+        })
     }
 }
 
 /// Systems involved in progress tracking.
+///
+/// Plugins that record work, such as [`ProgressTaskPlugin`], run before [`Check`](Self::Check).
+/// Their `schedule` must be the `check_schedule` of the tracking plugin, or a schedule that runs before it,
+/// for the work to be checked in the same frame.
+///
+/// Plugins that read trackers, such as [`MilestonePlugin`], run after [`Check`](Self::Check).
+/// Their `schedule` must be the `check_schedule` of the tracking plugin, or a later schedule
+/// that runs before the `reset_schedule`, so they don't see trackers after they're reset.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub enum ProgressSystems {
     /// System(s) that check for completed trackers.
     /// All progress should be recorded before this point.
     Check,
 
+    /// Smoothed progress values are updated, after [`Check`](Self::Check).
+    Smooth,
+
     /// Progress trackers are reset in preparation for the next tick.
     /// Progress should not be read after this point.
     Reset,
 }
 
+/// When progress trackers are reset by the tracking plugins.
+///
+/// Sources that record their full state every tick, such as [`ProgressTask`] and [`SharedProgress`],
+/// only record the change since the last tick into trackers with other policies,
+/// so their work is still counted once.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature="bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum ResetPolicy {
+    /// Trackers are reset every tick, in [`ProgressSystems::Reset`].
+    /// Work must be recorded again each tick.
+    #[default]
+    EveryTick,
+
+    /// Trackers are reset in [`ProgressSystems::Reset`], but only if they were complete when checked.
+    /// Work accumulates across ticks until completion.
+    OnDone,
+
+    /// Trackers are never reset automatically.
+    /// They can still be reset with [`ResetProgress`].
+    Manual,
+}
+
+/// How a [`Progress`] tracker behaves when its recorded work exceeds [`u64::MAX`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature="bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum OverflowPolicy {
+    /// Work saturates at [`u64::MAX`].
+    Saturate,
+
+    /// Panics in debug builds, and saturates at [`u64::MAX`] in release builds.
+    #[default]
+    DebugPanic,
+}
+
+impl OverflowPolicy {
+    fn add(self, a: u64, b: u64) -> u64 {
+        match a.checked_add(b) {
+            Some(v) => v,
+            None => {
+                debug_assert!(self == Self::Saturate, "progress tracker overflowed");
+                u64::MAX
+            },
+        }
+    }
+}
+
+/// The value returned by [`Progress::fract`] when a tracker has no work recorded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature="bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize))]
+pub enum EmptyFract {
+    /// Empty trackers report `0.0`, as if no work has been done.
+    #[default]
+    Zero,
+
+    /// Empty trackers report `1.0`, as if all work has been done.
+    One,
+}
+
+impl EmptyFract {
+    fn value(self) -> f32 {
+        match self {
+            Self::Zero => 0.0,
+            Self::One => 1.0,
+        }
+    }
+}
+
+/// While this resource exists, progress trackers for `T` are neither checked nor reset.
+///
+/// Work recorded while paused accumulates, and is checked once the resource is removed.
+#[derive(Resource)]
+pub struct ProgressPaused<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> ProgressPaused<T> {
+    /// Creates a new [`ProgressPaused`] resource.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for ProgressPaused<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Controls whether progress trackers for `T` are checked and reset.
+///
+/// Unlike [`ProgressPaused<T>`], this can stay in the world, and be toggled
+/// for pause menus or cutscenes. Trackers are evaluated as usual if it doesn't exist.
+/// Work recorded while disabled accumulates, and is checked once re-enabled.
+/// Sources of work added by plugins, such as [`ProgressTaskPlugin`], don't record anything while disabled.
+#[derive(Resource)]
+pub struct ProgressTrackingEnabled<T: ?Sized> {
+    /// Whether trackers for `T` are checked and reset.
+    pub enabled: bool,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressTrackingEnabled<T> {
+    /// Creates a new [`ProgressTrackingEnabled`] resource.
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Default for ProgressTrackingEnabled<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+fn valid_amount(amount: f64) -> bool {
+    amount.is_finite() && amount >= 0.0
+}
+
+fn tracking_enabled<T: ?Sized + Send + Sync + 'static>(
+    resource: Option<Res<ProgressTrackingEnabled<T>>>,
+) -> bool {
+    resource.is_none_or(|v| v.enabled)
+}
+
 /// Progress state.
 /// 
 /// Can be inserted as a [`Resource`] to track global progress,
 /// or as a [`Component`] to track progress for a single entity.
 #[derive(Component, Resource)]
+#[cfg_attr(feature="bevy_reflect", derive(Reflect), reflect(Component, Resource, Default, type_path = false))]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize), serde(bound = "", default))]
 pub struct Progress<T: ?Sized> {
     done: u64,
     total: u64,
+    weighted_done: f64,
+    weighted_total: f64,
+    float_done: f64,
+    float_total: f64,
+    hidden_done: u64,
+    hidden_total: u64,
+    skipped: u64,
+    expected_total: u64,
+    latch: bool,
+    overflow: OverflowPolicy,
+    empty: EmptyFract,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    phase: Phase,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    previous: (u64, u64),
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    previous_float: (f64, f64),
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    previous_fract: f32,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    milestone: usize,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    reset_requested: bool,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    started_at: Option<Instant>,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    stall: StallState,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    debounce: DebounceState,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    failure: FailureState,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    attribution: AttributionState,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
     _p1: PhantomData<T>,
 }
 
@@ -148,9 +1343,53 @@ impl<T: ?Sized> Progress<T> {
         Self {
             done: 0,
             total: 0,
+            weighted_done: 0.0,
+            weighted_total: 0.0,
+            float_done: 0.0,
+            float_total: 0.0,
+            hidden_done: 0,
+            hidden_total: 0,
+            skipped: 0,
+            latch: true,
+            overflow: OverflowPolicy::DebugPanic,
+            empty: EmptyFract::Zero,
+            phase: Phase::Fresh,
+            previous: (0, 0),
+            previous_float: (0.0, 0.0),
+            previous_fract: 0.0,
+            expected_total: 0,
+            milestone: 0,
+            reset_requested: false,
+            started_at: None,
+            stall: StallState::new(),
+            debounce: DebounceState::new(),
+            failure: FailureState::new(),
+            attribution: AttributionState::new(),
             _p1: PhantomData,
         }
     }
+
+    /// Sets whether [`Done`] is raised only once per completion.
+    ///
+    /// When enabled (the default), [`Done`] is raised the first time the tracker completes,
+    /// and not again until it has been incomplete or empty at least once.
+    /// When disabled, [`Done`] is raised every time the tracker is checked and found complete.
+    pub fn with_latch(mut self, latch: bool) -> Self {
+        self.latch = latch;
+        self
+    }
+
+    /// Sets the value returned by [`fract`](Self::fract) when no work has been recorded.
+    pub fn with_empty_fract(mut self, empty: EmptyFract) -> Self {
+        self.empty = empty;
+        self
+    }
+
+    /// Sets how the tracker behaves if recorded work exceeds [`u64::MAX`].
+    pub fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow = policy;
+        self
+    }
 }
 
 impl<T: ?Sized> Default for Progress<T> {
@@ -163,38 +1402,527 @@ impl<T: ?Sized> Default for Progress<T> {
 impl<T: ?Sized> Progress<T> {
     /// Records progress, including its total work and done work.
     pub fn track(&mut self, done: u32, total: u32) {
-        self.done += done as u64;
-        self.total += total as u64;
+        self.track_weighted(done, total, 1.0);
+    }
+
+    /// Records progress like [`track`](Self::track), but with each unit of work scaled by `weight`.
+    ///
+    /// Weights only affect the value returned by [`fract`](Self::fract).
+    /// The raw units of work returned by [`work`](Self::work) are unaffected.
+    /// Work with a negative or non-finite weight is ignored.
+    pub fn track_weighted(&mut self, done: u32, total: u32, weight: f32) {
+        if !valid_amount(weight as f64) { return }
+        self.record(done as u64, total as u64, weight as f64);
+    }
+
+    /// Records progress like [`track`](Self::track), with 64-bit units of work, such as byte counts.
+    pub fn track_u64(&mut self, done: u64, total: u64) {
+        self.record(done, total, 1.0);
+    }
+
+    /// Records progress like [`track`](Self::track), with `usize` units of work, such as collection lengths.
+    pub fn track_usize(&mut self, done: usize, total: usize) {
+        self.record(done as u64, total as u64, 1.0);
+    }
+
+    /// Records progress measured in fractional units, such as seconds of audio decoded.
+    ///
+    /// Fractional work counts towards [`fract`](Self::fract) and completion,
+    /// but is kept separately from the whole units returned by [`work`](Self::work).
+    /// It can be read with [`float_work`](Self::float_work) instead.
+    /// Negative or non-finite amounts of work are ignored.
+    pub fn track_f64(&mut self, done: f64, total: f64) {
+        if !valid_amount(done) || !valid_amount(total) { return }
+
+        self.float_done += done;
+        self.float_total += total;
+        self.weighted_done += done;
+        self.weighted_total += total;
+    }
+
+    /// Records hidden progress, for internal bookkeeping that shouldn't be shown to the user.
+    ///
+    /// Hidden work must be completed before the tracker is done, but doesn't count
+    /// towards [`fract`](Self::fract), so it doesn't cause progress bars to jump around.
+    /// It can be read with [`hidden_work`](Self::hidden_work).
+    pub fn track_hidden(&mut self, done: u32, total: u32) {
+        self.hidden_done = self.overflow.add(self.hidden_done, done as u64);
+        self.hidden_total = self.overflow.add(self.hidden_total, total as u64);
+    }
+
+    /// Removes previously recorded progress, for work that is no longer necessary.
+    ///
+    /// This saturates, so removing more work than was recorded leaves the tracker empty.
+    pub fn untrack(&mut self, done: u32, total: u32) {
+        self.untrack_weighted(done, total, 1.0);
+    }
+
+    /// Removes progress like [`untrack`](Self::untrack), for work recorded with [`track_weighted`](Self::track_weighted).
+    ///
+    /// `weight` should be the same as the weight the work was recorded with.
+    pub fn untrack_weighted(&mut self, done: u32, total: u32, weight: f32) {
+        if !valid_amount(weight as f64) { return }
+        self.unrecord(done as u64, total as u64, weight as f64);
+    }
+
+    /// Records `total` units of work, none of which have been completed.
+    pub fn add_total(&mut self, total: u32) {
+        self.record(0, total as u64, 1.0);
+    }
+
+    /// Records `done` units of completed work, without adding to the total.
+    ///
+    /// This is intended for use with [`add_total`](Self::add_total) or
+    /// [`set_expected_total`](Self::set_expected_total), where the total is declared elsewhere.
+    pub fn add_done(&mut self, done: u32) {
+        self.record(done as u64, 0, 1.0);
+    }
+
+    /// Declares the total units of work expected, before all of it has been recorded.
+    ///
+    /// The total reported by [`work`](Self::work) is never less than this value.
+    /// Unlike recorded work, this is not reset every tick, and persists until changed
+    /// or until the tracker is cleared with [`ResetProgress`] or [`CancelProgress`].
+    pub fn set_expected_total(&mut self, total: u32) {
+        self.expected_total = total as u64;
     }
 
     /// Returns the work that has been completed and the units of work 
     pub fn work(&self) -> (u64, u64) {
-        (self.done, self.total)
+        (self.done, self.total.max(self.expected_total))
     }
 
-    /// Returns the progress as a fraction, from `0.0` (no work done) to `1.0` (all work done).
-    pub fn fract(&self) -> f32 {
+    /// Returns the fractional work recorded with [`track_f64`](Self::track_f64), as completed and total work.
+    pub fn float_work(&self) -> (f64, f64) {
+        (self.float_done, self.float_total)
+    }
+
+    /// Returns the hidden work recorded with [`track_hidden`](Self::track_hidden), as completed and total work.
+    pub fn hidden_work(&self) -> (u64, u64) {
+        (self.hidden_done, self.hidden_total)
+    }
+
+    /// Returns the units of work that have been completed.
+    #[inline]
+    pub fn done(&self) -> u64 {
+        self.work().0
+    }
+
+    /// Returns the total units of work.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.work().1
+    }
+
+    /// Returns the units of work that have not been completed yet.
+    pub fn remaining(&self) -> u64 {
         let (done, total) = self.work();
-        return done as f32 / total as f32;
+        total.saturating_sub(done)
     }
 
-    fn done(&self) -> bool {
+    /// Returns `true` if all recorded work has been completed.
+    ///
+    /// This is also `true` if no work has been recorded.
+    pub fn is_done(&self) -> bool {
         let (done, total) = self.work();
-        return done >= total;
+        done >= total
+            && self.float_done >= self.float_total
+            && self.hidden_done >= self.hidden_total
+    }
+
+    /// Returns how long it has been since the tracker last started, or `None` if it hasn't started.
+    ///
+    /// A tracker starts when it first receives work, or receives more work after completing.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.started_at.map(|v| v.elapsed())
+    }
+
+    /// Returns the progress as a fraction, from `0.0` (no work done) to `1.0` (all work done).
+    ///
+    /// If work has been recorded with [`track_weighted`](Self::track_weighted), this takes weights into account.
+    /// If no work has been recorded, this returns the value set by [`with_empty_fract`](Self::with_empty_fract).
+    pub fn fract(&self) -> f32 {
+        self.fract_f64() as f32
+    }
+
+    /// Returns the progress as a fraction like [`fract`](Self::fract), with double precision.
+    ///
+    /// This is more precise than [`fract`](Self::fract) for very large amounts of work.
+    pub fn fract_f64(&self) -> f64 {
+        let total = self.weighted_total + self.expected_padding() as f64;
+        if total <= 0.0 { return self.empty.value() as f64 }
+
+        // Clamping passes NaN through, so it's counted as no progress instead
+        let fract = self.weighted_done / total;
+        if fract.is_nan() { return 0.0 }
+        fract.clamp(0.0, 1.0)
+    }
+
+    /// Returns the progress as a percentage, from `0.0` (no work done) to `100.0` (all work done).
+    pub fn percent(&self) -> f32 {
+        (self.fract_f64() * 100.0) as f32
+    }
+
+    /// Returns the progress in thousandths, from `0` (no work done) to `1000` (all work done).
+    ///
+    /// This rounds down, so it only returns `1000` once all work is done.
+    pub fn permille(&self) -> u16 {
+        (self.fract_f64() * 1000.0).floor() as u16
+    }
+
+    /// Returns the units of expected work that have not been recorded yet.
+    fn expected_padding(&self) -> u64 {
+        self.expected_total.saturating_sub(self.total)
+    }
+
+    fn record(&mut self, done: u64, total: u64, weight: f64) {
+        self.done = self.overflow.add(self.done, done);
+        self.total = self.overflow.add(self.total, total);
+        self.weighted_done += done as f64 * weight;
+        self.weighted_total += total as f64 * weight;
+    }
+
+    fn unrecord(&mut self, done: u64, total: u64, weight: f64) {
+        self.done = self.done.saturating_sub(done);
+        self.total = self.total.saturating_sub(total);
+        self.weighted_done = (self.weighted_done - done as f64 * weight).max(0.0);
+        self.weighted_total = (self.weighted_total - total as f64 * weight).max(0.0);
+    }
+
+    /// Returns `true` if the tracker completed non-empty work when last checked, and hasn't been reset since.
+    pub(crate) fn has_completed(&self) -> bool {
+        self.phase == Phase::Complete
+    }
+
+    /// Returns `true` if the tracker will be reset or cleared by [`reset_with`](Self::reset_with).
+    fn resets_with(&self, policy: ResetPolicy) -> bool {
+        self.reset_requested || match policy {
+            ResetPolicy::EveryTick => true,
+            ResetPolicy::OnDone => self.phase == Phase::Complete,
+            ResetPolicy::Manual => false,
+        }
+    }
+
+    /// Returns `true` if [`reset_with`](Self::reset_with) would modify the tracker.
+    ///
+    /// Trackers that are already empty are left alone, so they aren't marked as changed.
+    fn needs_reset(&self, policy: ResetPolicy) -> bool {
+        self.reset_requested || (self.resets_with(policy) && !self.is_reset())
+    }
+
+    /// Returns `true` if there is no work or failures for [`reset`](Self::reset) to remove.
+    fn is_reset(&self) -> bool {
+        self.done == 0 && self.total == 0
+            && self.weighted_done == 0.0 && self.weighted_total == 0.0
+            && self.float_done == 0.0 && self.float_total == 0.0
+            && self.hidden_done == 0 && self.hidden_total == 0
+            && self.skipped == 0
+            && self.failure.is_empty()
+    }
+
+    /// Resets the tracker if `policy` calls for it, or clears it if a reset was requested.
+    fn reset_with(&mut self, policy: ResetPolicy) {
+        if self.reset_requested {
+            self.clear();
+            return;
+        }
+
+        if self.resets_with(policy) { self.reset() }
+    }
+
+    fn reset(&mut self) {
+        self.reset_requested = false;
+        self.done = 0;
+        self.total = 0;
+        self.weighted_done = 0.0;
+        self.weighted_total = 0.0;
+        self.float_done = 0.0;
+        self.float_total = 0.0;
+        self.hidden_done = 0;
+        self.hidden_total = 0;
+        self.skipped = 0;
+        self.failure.reset();
+        self.attribution.reset();
+    }
+
+    /// Resets the tracker entirely, as if it had completed and been left empty.
+    fn clear(&mut self) {
+        self.reset();
+        self.phase = Phase::Idle;
+        self.previous = (0, 0);
+        self.previous_float = (0.0, 0.0);
+        self.previous_fract = 0.0;
+        self.expected_total = 0;
+        self.milestone = 0;
+        self.started_at = None;
+        self.stall.clear();
+        self.debounce = DebounceState::new();
+        self.failure = FailureState::new();
+        self.attribution.clear();
+    }
+
+    /// Returns `true` if checking the tracker can raise events even if it hasn't changed since the last check.
+    fn checks_unchanged(&self) -> bool {
+        match self.phase {
+            Phase::Fresh => true,
+            // Completion may be held back by a debounce
+            Phase::Running => self.stall.enabled() || self.is_done(),
+            Phase::Idle | Phase::Complete => !self.latch,
+        }
+    }
+
+    /// Advances the completion cycle, returning which events should be raised.
+    /// Completion is held back until `debounce` is satisfied.
+    fn check_with(&mut self, debounce: &CompletionDebounce) -> Checked {
+        let done = self.is_done();
+        let empty = self.work().1 == 0 && self.float_total == 0.0 && self.hidden_total == 0;
+
+        let previous = self.previous;
+        let previous_float = self.previous_float;
+        let updated = previous != self.work() || previous_float != self.float_work();
+        self.previous = self.work();
+        self.previous_float = self.float_work();
+        self.previous_fract = self.fract();
+
+        let started = !empty && match self.phase {
+            Phase::Fresh | Phase::Idle => true,
+            Phase::Complete => !done,
+            Phase::Running => false,
+        };
+
+        if started {
+            self.phase = Phase::Running;
+            self.milestone = 0;
+            self.started_at = Some(Instant::now());
+        }
+
+        let progressed = started || done || self.done > previous.0 || self.float_done > previous_float.0;
+        let stalled = self.stall.check(progressed);
+        let failed = self.failure.check();
+
+        let settled = self.debounce.check(debounce, self.started_at, done);
+
+        if !done { return Checked { started, updated, previous, stalled, failed, done: false } }
+
+        if matches!(self.phase, Phase::Fresh | Phase::Running) && !settled {
+            return Checked { started, updated, previous, stalled, failed, done: false };
+        }
+
+        let done = match self.phase {
+            Phase::Fresh | Phase::Running => true,
+            Phase::Idle | Phase::Complete => !self.latch,
+        };
+
+        self.phase = match empty {
+            true => Phase::Idle,
+            false => Phase::Complete,
+        };
+
+        Checked { started, updated, previous, stalled, failed, done }
+    }
+}
+
+/// Where a tracker is in its completion cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// The tracker has never been checked.
+    Fresh,
+    /// The tracker was empty when last checked.
+    Idle,
+    /// The tracker has received work, but has not completed.
+    Running,
+    /// The tracker has completed, and has not become incomplete since.
+    Complete,
+}
+
+/// The events to raise after checking a tracker.
+#[derive(Clone, Copy)]
+struct Checked {
+    started: bool,
+    updated: bool,
+    previous: (u64, u64),
+    stalled: Option<(u32, Duration)>,
+    failed: bool,
+    done: bool,
+}
+
+impl Checked {
+    /// Returns `true` if any events should be raised.
+    fn any(&self) -> bool {
+        self.started || self.updated || self.stalled.is_some() || self.failed || self.done
+    }
+}
+
+/// An observer event raised when a progress tracker first receives work.
+///
+/// This is raised again if the tracker receives more work after completing.
+#[derive(Event)]
+pub struct Started<T: ?Sized> {
+    done: u64,
+    total: u64,
+    label: Option<ProgressLabel<T>>,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Started<T> {
+    fn new(progress: &Progress<T>, label: Option<&ProgressLabel<T>>, entity: Option<Entity>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            done,
+            total,
+            label: label.cloned(),
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that had been completed and the total units of work when the tracker started.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the [`ProgressLabel`] of the tracker, if it had one.
+    #[inline]
+    pub fn label(&self) -> Option<&ProgressLabel<T>> {
+        self.label.as_ref()
+    }
+
+    /// Returns the entity whose tracker started, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
+
+/// An observer event raised when the work recorded by a progress tracker changes between checks.
+#[derive(Event)]
+pub struct Updated<T: ?Sized> {
+    done: u64,
+    total: u64,
+    delta_done: i64,
+    delta_total: i64,
+    fract: f32,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Updated<T> {
+    fn new(progress: &Progress<T>, previous: (u64, u64), entity: Option<Entity>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            done,
+            total,
+            delta_done: done as i64 - previous.0 as i64,
+            delta_total: total as i64 - previous.1 as i64,
+            fract: progress.fract(),
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that has been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the change in completed work and total work since the last check.
+    #[inline]
+    pub fn delta(&self) -> (i64, i64) {
+        (self.delta_done, self.delta_total)
+    }
+
+    /// Returns the new progress as a fraction, as returned by [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns the entity whose tracker was updated, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
     }
 }
 
 /// An observer event raised when a progress tracker completes.
 #[derive(Event)]
+#[cfg_attr(feature="bevy_reflect", derive(Reflect), reflect(type_path = false))]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize), serde(bound = ""))]
 pub struct Done<T: ?Sized> {
-    work: u64,
+    done: u64,
+    total: u64,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    label: Option<ProgressLabel<T>>,
+    entity: Option<Entity>,
+    elapsed: Duration,
+    overshot: bool,
+    completed: bool,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
     _p1: PhantomData<T>,
 }
 
 impl<T: ?Sized> Done<T> {
-    /// Returns the amount of work done.
+    fn new(progress: &Progress<T>, label: Option<&ProgressLabel<T>>, entity: Option<Entity>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            done,
+            total,
+            label: label.cloned(),
+            entity,
+            elapsed: progress.elapsed().unwrap_or_default(),
+            overshot: done > total || progress.float_done > progress.float_total,
+            completed: progress.has_completed(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that had been completed and the total units of work at completion.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the [`ProgressLabel`] of the tracker, if it had one.
+    #[inline]
+    pub fn label(&self) -> Option<&ProgressLabel<T>> {
+        self.label.as_ref()
+    }
+
+    /// Returns the entity whose tracker completed, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// Returns how long the tracker took to complete, from when it started.
+    ///
+    /// This is zero if the tracker completed without ever receiving work.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns `true` if more work was completed than the total,
+    /// which usually indicates that some work was recorded incorrectly.
+    #[inline]
+    pub fn overshot(&self) -> bool {
+        self.overshot
+    }
+
+    /// Returns `true` if the tracker had work recorded, and completed it.
+    ///
+    /// This is `false` for trackers that are done because they're empty,
+    /// such as a tracker that is checked before any work is recorded in it.
     #[inline]
-    pub fn work(&self) -> u64 {
-        self.work
+    pub fn has_completed(&self) -> bool {
+        self.completed
     }
 }
\ No newline at end of file
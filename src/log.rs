@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_utils::{tracing::info, Duration, Instant};
+use crate::{EtaEstimator, Progress, ProgressPaused, ProgressSystems};
+
+/// Periodically logs the state of the [`Progress<T>`] resource at the `info` level.
+///
+/// The rate of work and estimated time remaining are included
+/// if an [`EtaEstimator<T>`] exists, such as from [`EtaPlugin<T>`](crate::EtaPlugin).
+pub struct ProgressLogPlugin<T: ?Sized> {
+    /// The schedule in which progress is logged.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// How often progress is logged.
+    pub interval: LogInterval,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressLogPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            interval: LogInterval::Time(Duration::from_millis(500)),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> ProgressLogPlugin<T> {
+    /// Sets how often progress is logged.
+    pub fn with_interval(mut self, interval: LogInterval) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressLogPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LogState::<T>::new(self.interval));
+
+        app.add_systems(self.schedule, log_system::<T>
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+/// How often [`ProgressLogPlugin`] logs progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogInterval {
+    /// Progress is logged at most once per duration.
+    Time(Duration),
+
+    /// Progress is logged each time the fraction of work done advances by this much,
+    /// such as `0.05` to log every 5%.
+    Fraction(f32),
+}
+
+#[derive(Resource)]
+struct LogState<T: ?Sized> {
+    interval: LogInterval,
+    at: Option<Instant>,
+    fract: f32,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> LogState<T> {
+    fn new(interval: LogInterval) -> Self {
+        Self {
+            interval,
+            at: None,
+            fract: 0.0,
+            _p1: PhantomData,
+        }
+    }
+}
+
+fn log_system<T: ?Sized + Send + Sync + 'static>(
+    mut state: ResMut<LogState<T>>,
+    resource: Option<Res<Progress<T>>>,
+    estimator: Option<Res<EtaEstimator<T>>>,
+) {
+    let resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    // Nothing to report for an empty tracker
+    if resource.total() == 0 { return }
+
+    let now = Instant::now();
+    let fract = resource.fract();
+
+    // Less progress means a new set of work, so logging starts over
+    if fract < state.fract {
+        state.at = None;
+        state.fract = 0.0;
+    }
+
+    let due = match (state.interval, state.at) {
+        (_, None) => true,
+        (LogInterval::Time(duration), Some(at)) => now.duration_since(at) >= duration,
+        (LogInterval::Fraction(step), Some(_)) => fract - state.fract >= step || (fract >= 1.0 && state.fract < 1.0),
+    };
+
+    if !due { return }
+    state.at = Some(now);
+    state.fract = fract;
+
+    let name = bevy_utils::get_short_name(std::any::type_name::<T>());
+    let rate = estimator.as_ref().and_then(|v| v.rate());
+    let eta = estimator.as_ref().and_then(|v| v.estimated_remaining());
+
+    match (rate, eta) {
+        (Some(rate), Some(eta)) => info!("{name}: {}, {rate:.1}/s, {:.1}s remaining", *resource, eta.as_secs_f32()),
+        (Some(rate), None) => info!("{name}: {}, {rate:.1}/s", *resource),
+        _ => info!("{name}: {}", *resource),
+    }
+}
@@ -0,0 +1,76 @@
+use bevy_ecs::{prelude::*, schedule::SystemConfigs};
+use crate::{cancel, despawn, reset, track, Progress, ProgressPaused, ProgressSystems, ResetPolicy};
+use crate::{resource_check_system, resource_reset_system, entity_check_system, entity_reset_system};
+use crate::{resource_progress_changed, tracking_enabled};
+
+/// Sets up progress tracking for `T` in a [`World`], without an [`App`](bevy_app::App).
+///
+/// This is for embedding trackers in a plain `bevy_ecs` world, such as in tools or servers.
+/// It registers the observers that handle [`TrackWork<T>`](crate::TrackWork),
+/// [`ResetProgress<T>`](crate::ResetProgress), [`CancelProgress<T>`](crate::CancelProgress),
+/// and [`DespawnOnDone<T>`](crate::DespawnOnDone), for both the resource and components.
+/// The systems are added separately, with [`resource_progress_check_system`],
+/// [`entity_progress_check_system`], and the matching reset systems.
+///
+/// The tracking plugins do this for you, and shouldn't be used alongside this.
+/// Features that need plugins, such as the registry, aren't set up.
+/// To also write [`Done<T>`](crate::Done) into an [`Events`] buffer, insert the resource yourself.
+pub fn init_progress_tracking<T: Send + Sync + 'static>(world: &mut World) {
+    world.init_resource::<track::PendingWork<T>>();
+
+    world.observe(cancel::resource_cancel_observer::<T>);
+    world.observe(reset::resource_reset_observer::<T>);
+    world.observe(track::resource_track_observer::<T>);
+
+    world.observe(cancel::entity_cancel_observer::<T, Progress<T>>);
+    world.observe(reset::entity_reset_observer::<T, Progress<T>>);
+    world.observe(track::entity_track_observer::<T>);
+    world.observe(despawn::despawn_on_done_observer::<T>);
+}
+
+/// Returns the systems that check the [`Progress<T>`] resource, for adding to a [`Schedule`].
+///
+/// This applies work recorded with [`TrackWork<T>`](crate::TrackWork)
+/// and raises events like [`Done<T>`](crate::Done), in [`ProgressSystems::Check`]. Requires [`init_progress_tracking`] to have been called.
+pub fn resource_progress_check_system<T: Send + Sync + 'static>() -> SystemConfigs {
+    (
+        track::resource_pending_system::<T>,
+        resource_check_system::<T>
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>)
+            .run_if(resource_progress_changed::<T>),
+    ).chain().in_set(ProgressSystems::Check)
+}
+
+/// Returns the system that resets the [`Progress<T>`] resource according to `policy`, for adding to a [`Schedule`].
+///
+/// This runs in [`ProgressSystems::Reset`], and should run after the check systems.
+pub fn resource_progress_reset_system<T: Send + Sync + 'static>(policy: ResetPolicy) -> SystemConfigs {
+    resource_reset_system::<T>(policy)
+        .in_set(ProgressSystems::Reset)
+        .run_if(not(resource_exists::<ProgressPaused<T>>))
+        .run_if(tracking_enabled::<T>)
+}
+
+/// Returns the systems that check [`Progress<T>`] components, for adding to a [`Schedule`].
+///
+/// This applies work recorded with [`TrackWork<T>`](crate::TrackWork)
+/// and raises events like [`Done<T>`](crate::Done), in [`ProgressSystems::Check`]. Requires [`init_progress_tracking`] to have been called.
+pub fn entity_progress_check_system<T: Send + Sync + 'static>() -> SystemConfigs {
+    (
+        track::entity_pending_system::<T, Progress<T>>,
+        entity_check_system::<T, Progress<T>>
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>),
+    ).chain().in_set(ProgressSystems::Check)
+}
+
+/// Returns the system that resets [`Progress<T>`] components according to `policy`, for adding to a [`Schedule`].
+///
+/// This runs in [`ProgressSystems::Reset`], and should run after the check systems.
+pub fn entity_progress_reset_system<T: Send + Sync + 'static>(policy: ResetPolicy) -> SystemConfigs {
+    entity_reset_system::<T, Progress<T>>(policy)
+        .in_set(ProgressSystems::Reset)
+        .run_if(not(resource_exists::<ProgressPaused<T>>))
+        .run_if(tracking_enabled::<T>)
+}
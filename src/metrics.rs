@@ -0,0 +1,137 @@
+use std::{fmt::Write, net::SocketAddr};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use crate::{server, ProgressRegistry, ProgressSystems};
+
+/// Serves the state of every tracker in the [`ProgressRegistry`] as Prometheus metrics over HTTP.
+///
+/// This lets operators running many headless servers scrape and aggregate progress.
+/// Any request to the address is answered in the Prometheus text format, with these metrics,
+/// each labelled with the `type` of the tracker:
+///
+/// - `bevy_progress_fraction`, a gauge of the progress as a fraction.
+/// - `bevy_progress_done` and `bevy_progress_total`, gauges of the completed and total units of work.
+/// - `bevy_progress_rate`, a gauge of the units of work completed per second,
+///   for trackers with an [`EtaEstimator`](crate::EtaEstimator).
+/// - `bevy_progress_completions_total`, a counter of how many times the tracker has completed,
+///   as counted by [`RegisteredProgress::completions`](crate::RegisteredProgress::completions).
+///
+/// Only trackers stored as resources are included.
+/// Requests are handled on a background thread, from a snapshot taken after
+/// [`ProgressSystems::Check`] in [`PostUpdate`], so serving requests never blocks the app.
+pub struct ProgressMetricsPlugin {
+    /// The address to listen on.
+    /// Defaults to `127.0.0.1:9464`.
+    pub address: SocketAddr,
+}
+
+impl Default for ProgressMetricsPlugin {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::from(([127, 0, 0, 1], 9464)),
+        }
+    }
+}
+
+impl ProgressMetricsPlugin {
+    /// Listens on `address`.
+    pub fn with_address(mut self, address: impl Into<SocketAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+}
+
+impl Plugin for ProgressMetricsPlugin {
+    fn build(&self, app: &mut App) {
+        let response = server::Response {
+            status: "200 OK",
+            content_type: "text/plain; version=0.0.4",
+            body: String::new(),
+        };
+
+        let response = match server::spawn("progress metrics exporter", self.address, response) {
+            Some(v) => v,
+            None => return,
+        };
+
+        app.insert_resource(MetricsResponse(response));
+        app.add_systems(PostUpdate, metrics_snapshot_system
+            .after(ProgressSystems::Check));
+    }
+}
+
+/// The latest metrics, shared with the thread serving requests.
+#[derive(Resource)]
+struct MetricsResponse(server::SharedResponse);
+
+fn metrics_snapshot_system(world: &World) {
+    let (registry, response) = match (world.get_resource::<ProgressRegistry>(), world.get_resource::<MetricsResponse>()) {
+        (Some(registry), Some(response)) => (registry, response),
+        _ => return,
+    };
+
+    let mut fraction = String::new();
+    let mut done = String::new();
+    let mut total = String::new();
+    let mut rate = String::new();
+    let mut completed = String::new();
+
+    for entry in registry.iter() {
+        let info = match entry.resource(world) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let label = escape(entry.type_name());
+        let (work_done, work_total) = info.work();
+
+        let _ = writeln!(fraction, "bevy_progress_fraction{{type=\"{label}\"}} {}", number(info.fract() as f64));
+        let _ = writeln!(done, "bevy_progress_done{{type=\"{label}\"}} {work_done}");
+        let _ = writeln!(total, "bevy_progress_total{{type=\"{label}\"}} {work_total}");
+        let _ = writeln!(completed, "bevy_progress_completions_total{{type=\"{label}\"}} {}", entry.completions());
+
+        if let Some(value) = entry.rate(world) {
+            let _ = writeln!(rate, "bevy_progress_rate{{type=\"{label}\"}} {}", number(value));
+        }
+    }
+
+    let mut body = String::new();
+    metric(&mut body, "bevy_progress_fraction", "gauge", "Progress as a fraction, from 0 to 1.", &fraction);
+    metric(&mut body, "bevy_progress_done", "gauge", "Completed units of work.", &done);
+    metric(&mut body, "bevy_progress_total", "gauge", "Total units of work.", &total);
+    metric(&mut body, "bevy_progress_rate", "gauge", "Units of work completed per second.", &rate);
+    metric(&mut body, "bevy_progress_completions_total", "counter", "Number of times the tracker has completed.", &completed);
+
+    response.0.lock().unwrap().body = body;
+}
+
+fn metric(body: &mut String, name: &str, kind: &str, help: &str, samples: &str) {
+    let _ = writeln!(body, "# HELP {name} {help}");
+    let _ = writeln!(body, "# TYPE {name} {kind}");
+    body.push_str(samples);
+}
+
+fn number(value: f64) -> String {
+    match value {
+        v if v.is_nan() => "NaN".into(),
+        v if v == f64::INFINITY => "+Inf".into(),
+        v if v == f64::NEG_INFINITY => "-Inf".into(),
+        v => v.to_string(),
+    }
+}
+
+/// Escapes `value` for use as a label value.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
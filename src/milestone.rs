@@ -0,0 +1,130 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{Progress, ProgressPaused, ProgressSystems};
+
+/// Raises [`Milestone<T>`] events when [`Progress<T>`] trackers pass configured fractions.
+pub struct MilestonePlugin<T: ?Sized> {
+    /// The schedule in which milestones are checked.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// The fractions at which milestones are raised, from `0.0` to `1.0`.
+    pub milestones: Vec<f32>,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> MilestonePlugin<T> {
+    /// Creates a new [`MilestonePlugin`], raising milestones at each fraction in `milestones`.
+    pub fn new(milestones: impl IntoIterator<Item = f32>) -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            milestones: milestones.into_iter().collect(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Default for MilestonePlugin<T> {
+    fn default() -> Self {
+        Self::new([0.25, 0.5, 0.75])
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for MilestonePlugin<T> {
+    fn build(&self, app: &mut App) {
+        let mut milestones = self.milestones.clone();
+        milestones.sort_by(f32::total_cmp);
+
+        app.insert_resource(Milestones::<T> {
+            milestones,
+            _p1: PhantomData,
+        });
+
+        app.add_systems(self.schedule, (
+            resource_milestone_system::<T>,
+            entity_milestone_system::<T>,
+        ).after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+#[derive(Resource)]
+struct Milestones<T: ?Sized> {
+    milestones: Vec<f32>,
+    _p1: PhantomData<T>,
+}
+
+fn resource_milestone_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    milestones: Res<Milestones<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    for &milestone in passed(&milestones.milestones, resource.bypass_change_detection()) {
+        commands.trigger(Milestone::<T>::new(milestone, None));
+    }
+}
+
+fn entity_milestone_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    milestones: Res<Milestones<T>>,
+    mut query: Query<(Entity, &mut Progress<T>)>,
+) {
+    for (entity, mut tracker) in &mut query {
+        for &milestone in passed(&milestones.milestones, tracker.bypass_change_detection()) {
+            commands.trigger_targets(Milestone::<T>::new(milestone, Some(entity)), [entity]);
+        }
+    }
+}
+
+/// Returns the milestones passed since the last call, advancing the tracker's milestone index.
+fn passed<'a, T: ?Sized>(milestones: &'a [f32], progress: &mut Progress<T>) -> &'a [f32] {
+    let start = progress.milestone.min(milestones.len());
+    let fract = progress.previous_fract;
+
+    let count = milestones[start..].iter()
+        .take_while(|v| fract >= **v)
+        .count();
+
+    progress.milestone = start + count;
+    &milestones[start..start + count]
+}
+
+/// An observer event raised when a progress tracker's fraction passes a milestone.
+///
+/// Each milestone is raised once per completion cycle, when the tracker is checked.
+/// Milestones are configured with [`MilestonePlugin<T>`].
+#[derive(Event)]
+pub struct Milestone<T: ?Sized> {
+    fract: f32,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Milestone<T> {
+    fn new(fract: f32, entity: Option<Entity>) -> Self {
+        Self {
+            fract,
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the fraction of the milestone that was passed.
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns the entity whose tracker passed the milestone, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
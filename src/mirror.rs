@@ -0,0 +1,112 @@
+use std::{marker::PhantomData, sync::{Arc, Mutex}};
+use bevy_app::{prelude::*, AppLabel, InternedAppLabel};
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Mirrors the [`Progress<T>`] resource of a sub-app into the [`Progress<T>`] resource of the main world.
+///
+/// This lets trackers live in sub-apps, such as a dedicated loading sub-app or the `RenderApp`,
+/// while the main world's observers and UI see a unified view.
+/// The sub-app's tracker is copied after [`ProgressSystems::Check`] in the sub-app,
+/// and added to the main world tracker right before [`ProgressSystems::Check`] in the main world.
+/// Sub-apps update after the main world, so the main world sees it one frame later.
+///
+/// The sub-app doesn't need tracking plugins of its own, but can have them.
+/// Only the resource is mirrored, not components.
+///
+/// This must be added after the sub-app, and does nothing if there is no such sub-app.
+pub struct SubAppProgressPlugin<T: ?Sized> {
+    /// The sub-app containing the tracker.
+    pub sub_app: InternedAppLabel,
+
+    /// The schedule in the sub-app in which the tracker is copied.
+    /// If the sub-app has tracking plugins, this should be their `check_schedule`.
+    pub sub_app_schedule: InternedScheduleLabel,
+
+    /// The schedule in which the main world tracker is updated.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> SubAppProgressPlugin<T> {
+    /// Creates a new [`SubAppProgressPlugin`], copying the tracker in `sub_app` during `sub_app_schedule`.
+    pub fn new(sub_app: impl AppLabel, sub_app_schedule: impl ScheduleLabel) -> Self {
+        Self {
+            sub_app: sub_app.intern(),
+            sub_app_schedule: sub_app_schedule.intern(),
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for SubAppProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let mirror = MirroredProgress::<T>::default();
+
+        let sub_app = match app.get_sub_app_mut(self.sub_app) {
+            Some(v) => v,
+            None => return,
+        };
+
+        sub_app.insert_resource(mirror.clone());
+        sub_app.add_systems(self.sub_app_schedule, mirror_capture_system::<T>
+            .after(ProgressSystems::Check)
+            .before(ProgressSystems::Reset));
+
+        app.insert_resource(mirror);
+        app.add_systems(self.schedule, mirror_apply_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+/// The latest copy of the sub-app's tracker, shared between the main world and the sub-app.
+#[derive(Resource)]
+struct MirroredProgress<T: ?Sized> {
+    inner: Arc<Mutex<Option<Progress<T>>>>,
+}
+
+impl<T: ?Sized> Default for MirroredProgress<T> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(None)),
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for MirroredProgress<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+fn mirror_capture_system<T: ?Sized + Send + Sync + 'static>(
+    mirror: Res<MirroredProgress<T>>,
+    resource: Option<Res<Progress<T>>>,
+) {
+    *mirror.inner.lock().unwrap() = resource.map(|v| v.clone());
+}
+
+fn mirror_apply_system<T: ?Sized + Send + Sync + 'static>(
+    mirror: Res<MirroredProgress<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    if let Some(mirrored) = mirror.inner.lock().unwrap().as_ref() {
+        record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+            *progress += mirrored;
+        });
+    }
+}
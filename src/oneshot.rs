@@ -0,0 +1,107 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::ScheduleLabel, system::SystemId};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Extension trait for tracking systems as units of work, such as initialization steps.
+///
+/// Each tracked system contributes one unit of total work to the [`Progress<T>`] resource,
+/// which is done once the system has run. This provides initialization progress out of the box.
+pub trait AppTrackedSystemsExt {
+    /// Adds `system` to `schedule`, as a unit of work for `T`.
+    ///
+    /// This is most useful with [`Startup`], or with schedules like `OnEnter` that run once.
+    fn add_tracked_system<T: Send + Sync + 'static, M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self;
+
+    /// Registers `system` as a one-shot system, as a unit of work for `T`.
+    ///
+    /// The returned [`SystemId`] can be run with [`Commands::run_system`] or [`World::run_system`].
+    fn register_tracked_system<T: Send + Sync + 'static, M>(
+        &mut self,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> SystemId;
+}
+
+impl AppTrackedSystemsExt for App {
+    fn add_tracked_system<T: Send + Sync + 'static, M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        system: impl IntoSystem<(), (), M>,
+    ) -> &mut Self {
+        let index = add_unit::<T>(self);
+        self.add_systems(schedule, system.pipe(mark_system::<T>(index)))
+    }
+
+    fn register_tracked_system<T: Send + Sync + 'static, M>(
+        &mut self,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> SystemId {
+        let index = add_unit::<T>(self);
+        self.world_mut().register_system(system.pipe(mark_system::<T>(index)))
+    }
+}
+
+fn add_unit<T: Send + Sync + 'static>(app: &mut App) -> usize {
+    if !app.world().contains_resource::<TrackedSystems<T>>() {
+        app.insert_resource(TrackedSystems::<T>::new());
+
+        app.add_systems(PostUpdate, tracked_systems_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+
+    let mut tracked = app.world_mut().resource_mut::<TrackedSystems<T>>();
+    tracked.ran.push(false);
+    tracked.ran.len() - 1
+}
+
+fn mark_system<T: ?Sized + Send + Sync + 'static>(
+    index: usize,
+) -> impl FnMut(In<()>, ResMut<TrackedSystems<T>>) {
+    move |_, mut tracked| {
+        tracked.ran[index] = true;
+    }
+}
+
+fn tracked_systems_system<T: ?Sized + Send + Sync + 'static>(
+    tracked: Res<TrackedSystems<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let (done, total) = tracked.work();
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        progress.track_usize(done, total);
+    });
+}
+
+/// The systems tracked as units of work for `T`, added with [`AppTrackedSystemsExt`].
+#[derive(Resource)]
+pub struct TrackedSystems<T: ?Sized> {
+    ran: Vec<bool>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> TrackedSystems<T> {
+    fn new() -> Self {
+        Self {
+            ran: Vec::new(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the number of tracked systems that have run, and the total number of tracked systems.
+    pub fn work(&self) -> (usize, usize) {
+        (self.ran.iter().filter(|v| **v).count(), self.ran.len())
+    }
+}
@@ -0,0 +1,159 @@
+use std::{iter::Sum, ops::{Add, AddAssign}};
+use crate::Progress;
+
+impl<T: ?Sized> Clone for Progress<T> {
+    fn clone(&self) -> Self {
+        Self {
+            done: self.done,
+            total: self.total,
+            weighted_done: self.weighted_done,
+            weighted_total: self.weighted_total,
+            float_done: self.float_done,
+            float_total: self.float_total,
+            hidden_done: self.hidden_done,
+            hidden_total: self.hidden_total,
+            skipped: self.skipped,
+            expected_total: self.expected_total,
+            latch: self.latch,
+            overflow: self.overflow,
+            empty: self.empty,
+            phase: self.phase,
+            previous: self.previous,
+            previous_float: self.previous_float,
+            previous_fract: self.previous_fract,
+            milestone: self.milestone,
+            reset_requested: self.reset_requested,
+            started_at: self.started_at,
+            stall: self.stall.clone(),
+            debounce: self.debounce.clone(),
+            failure: self.failure.clone(),
+            attribution: self.attribution.clone(),
+            _p1: self._p1,
+        }
+    }
+}
+
+/// Trackers are equal if they have recorded the same work.
+/// Their configuration and completion cycle are not compared.
+impl<T: ?Sized> PartialEq for Progress<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.work() == other.work()
+            && self.weighted_done == other.weighted_done
+            && self.weighted_total + self.expected_padding() as f64 == other.weighted_total + other.expected_padding() as f64
+            && self.float_work() == other.float_work()
+            && self.hidden_work() == other.hidden_work()
+            && self.skipped == other.skipped
+            && self.failed() == other.failed()
+    }
+}
+
+/// Adds the work recorded by `other` to the tracker, including expected and failed work.
+impl<T: ?Sized> AddAssign<&Progress<T>> for Progress<T> {
+    fn add_assign(&mut self, other: &Progress<T>) {
+        let (done, total) = other.work();
+        self.done = self.overflow.add(self.done, done);
+        self.total = self.overflow.add(self.total, total);
+        self.weighted_done += other.weighted_done;
+        self.weighted_total += other.weighted_total + other.expected_padding() as f64;
+        self.float_done += other.float_done;
+        self.float_total += other.float_total;
+        self.hidden_done = self.overflow.add(self.hidden_done, other.hidden_done);
+        self.hidden_total = self.overflow.add(self.hidden_total, other.hidden_total);
+        self.skipped = self.overflow.add(self.skipped, other.skipped);
+        self.failure.failed += other.failure.failed;
+        self.failure.reasons.extend(other.failure.reasons.iter().cloned());
+    }
+}
+
+impl<T: ?Sized> AddAssign for Progress<T> {
+    #[inline]
+    fn add_assign(&mut self, other: Progress<T>) {
+        *self += &other;
+    }
+}
+
+impl<T: ?Sized> Add<&Progress<T>> for Progress<T> {
+    type Output = Progress<T>;
+
+    #[inline]
+    fn add(mut self, other: &Progress<T>) -> Self::Output {
+        self += other;
+        self
+    }
+}
+
+impl<T: ?Sized> Add for Progress<T> {
+    type Output = Progress<T>;
+
+    #[inline]
+    fn add(mut self, other: Progress<T>) -> Self::Output {
+        self += &other;
+        self
+    }
+}
+
+impl<T: ?Sized> Sum for Progress<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Progress::new(), Add::add)
+    }
+}
+
+impl<'a, T: ?Sized> Sum<&'a Progress<T>> for Progress<T> {
+    fn sum<I: Iterator<Item = &'a Progress<T>>>(iter: I) -> Self {
+        iter.fold(Progress::new(), Add::add)
+    }
+}
+
+/// Records each item as done and total work, like [`Progress::track_u64`].
+impl<T: ?Sized> Extend<(u64, u64)> for Progress<T> {
+    fn extend<I: IntoIterator<Item = (u64, u64)>>(&mut self, iter: I) {
+        for (done, total) in iter {
+            self.record(done, total, 1.0);
+        }
+    }
+}
+
+/// Records each item as one unit of work, which is done if the item is `true`.
+impl<T: ?Sized> Extend<bool> for Progress<T> {
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for done in iter {
+            self.record(done as u64, 1, 1.0);
+        }
+    }
+}
+
+/// Records each item as one unit of work, like [`Progress::track_result`].
+impl<T: ?Sized, V, E> Extend<Result<V, E>> for Progress<T> {
+    fn extend<I: IntoIterator<Item = Result<V, E>>>(&mut self, iter: I) {
+        for result in iter {
+            self.track_result(&result);
+        }
+    }
+}
+
+/// Creates a tracker with each item recorded as done and total work, like [`Progress::track_u64`].
+impl<T: ?Sized> FromIterator<(u64, u64)> for Progress<T> {
+    fn from_iter<I: IntoIterator<Item = (u64, u64)>>(iter: I) -> Self {
+        let mut progress = Progress::new();
+        progress.extend(iter);
+        progress
+    }
+}
+
+/// Creates a tracker with each item recorded as one unit of work, which is done if the item is `true`.
+impl<T: ?Sized> FromIterator<bool> for Progress<T> {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut progress = Progress::new();
+        progress.extend(iter);
+        progress
+    }
+}
+
+/// Creates a tracker with each item recorded as one unit of work, like [`Progress::track_result`].
+impl<T: ?Sized, V, E> FromIterator<Result<V, E>> for Progress<T> {
+    fn from_iter<I: IntoIterator<Item = Result<V, E>>>(iter: I) -> Self {
+        let mut progress = Progress::new();
+        progress.extend(iter);
+        progress
+    }
+}
@@ -0,0 +1,164 @@
+use bevy_ecs::{prelude::*, query::QueryData, system::{Deferred, SystemBuffer, SystemMeta, SystemParam}};
+use bevy_utils::Duration;
+use crate::{EtaEstimator, Progress, ProgressLabel};
+
+/// A [`SystemParam`] for recording work into the [`Progress<T>`] resource.
+///
+/// If the resource doesn't exist, work is buffered, and the resource is
+/// initialized with the buffered work when the system's commands are applied.
+#[derive(SystemParam)]
+pub struct ProgressWriter<'w, 's, T: ?Sized + Send + Sync + 'static> {
+    resource: Option<ResMut<'w, Progress<T>>>,
+    pending: Deferred<'s, PendingProgress<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> ProgressWriter<'_, '_, T> {
+    /// Records progress, including its total work and done work.
+    pub fn track(&mut self, done: u32, total: u32) {
+        match self.resource.as_mut() {
+            Some(resource) => resource.track(done, total),
+            None => self.pending.get().track(done, total),
+        }
+    }
+
+    /// Records `done` units of completed work, without adding to the total.
+    pub fn add_done(&mut self, done: u32) {
+        self.track(done, 0);
+    }
+
+    /// Records `total` units of work, none of which have been completed.
+    pub fn add_total(&mut self, total: u32) {
+        self.track(0, total);
+    }
+
+    /// Declares the total units of work expected, as in [`Progress::set_expected_total`].
+    pub fn set_total(&mut self, total: u32) {
+        match self.resource.as_mut() {
+            Some(resource) => resource.set_expected_total(total),
+            None => self.pending.get().set_expected_total(total),
+        }
+    }
+}
+
+struct PendingProgress<T: ?Sized>(Option<Progress<T>>);
+
+impl<T: ?Sized> PendingProgress<T> {
+    fn get(&mut self) -> &mut Progress<T> {
+        self.0.get_or_insert_with(Progress::new)
+    }
+}
+
+impl<T: ?Sized> Default for PendingProgress<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> SystemBuffer for PendingProgress<T> {
+    fn apply(&mut self, _system_meta: &SystemMeta, world: &mut World) {
+        let pending = match self.0.take() {
+            Some(v) => v,
+            None => return,
+        };
+
+        match world.get_resource_mut::<Progress<T>>() {
+            Some(mut resource) => {
+                resource.record(pending.done, pending.total, 1.0);
+                if pending.expected_total > 0 {
+                    resource.expected_total = pending.expected_total;
+                }
+            },
+
+            None => world.insert_resource(pending),
+        }
+    }
+}
+
+/// A read-only [`SystemParam`] for reading a [`Progress<T>`] tracker.
+///
+/// This reads the [`Progress<T>`] resource if it exists, or otherwise the [`Progress<T>`]
+/// component of the only entity that has one. If there is no such tracker, or more than one
+/// entity has a [`Progress<T>`] component, all methods return `None`.
+#[derive(SystemParam)]
+pub struct ProgressReader<'w, 's, T: ?Sized + Send + Sync + 'static> {
+    resource: Option<Res<'w, Progress<T>>>,
+    entities: Query<'w, 's, &'static Progress<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> ProgressReader<'_, '_, T> {
+    /// Returns the tracker being read, if there is one.
+    pub fn get(&self) -> Option<&Progress<T>> {
+        match self.resource.as_deref() {
+            Some(resource) => Some(resource),
+            None => self.entities.get_single().ok(),
+        }
+    }
+
+    /// Returns the progress as a fraction, as in [`Progress::fract`].
+    pub fn fract(&self) -> Option<f32> {
+        self.get().map(|v| v.fract())
+    }
+
+    /// Returns `true` if all recorded work has been completed, as in [`Progress::is_done`].
+    pub fn is_done(&self) -> Option<bool> {
+        self.get().map(|v| v.is_done())
+    }
+
+    /// Returns the work that has been completed and the total units of work, as in [`Progress::work`].
+    pub fn work(&self) -> Option<(u64, u64)> {
+        self.get().map(|v| v.work())
+    }
+
+    /// Returns `true` if the tracker is stalled, as in [`Progress::stalled`].
+    pub fn stalled(&self) -> Option<bool> {
+        self.get().map(|v| v.stalled())
+    }
+}
+
+/// A [`QueryData`] for reading an entity's [`Progress<T>`] component,
+/// along with its [`ProgressLabel<T>`] and [`EtaEstimator<T>`] components, if it has them.
+///
+/// Query items have methods for the values commonly shown in UI.
+#[derive(QueryData)]
+pub struct ProgressRef<T: ?Sized + Send + Sync + 'static> {
+    /// The entity's tracker.
+    pub progress: &'static Progress<T>,
+
+    /// The entity's label, if it has one.
+    pub label: Option<&'static ProgressLabel<T>>,
+
+    /// The entity's rate estimator, if it has one.
+    pub eta: Option<&'static EtaEstimator<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> ProgressRefItem<'_, T> {
+    /// Returns the progress as a fraction, as in [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.progress.fract()
+    }
+
+    /// Returns `true` if all recorded work has been completed, as in [`Progress::is_done`].
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.progress.is_done()
+    }
+
+    /// Returns the work that has been completed and the total units of work, as in [`Progress::work`].
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        self.progress.work()
+    }
+
+    /// Returns the human-readable name of the tracker, if it has a [`ProgressLabel<T>`].
+    pub fn label(&self) -> Option<&str> {
+        self.label.map(|v| v.name())
+    }
+
+    /// Returns the estimated time until all work is done, if it has an [`EtaEstimator<T>`].
+    ///
+    /// See [`EtaEstimator::estimated_remaining`].
+    pub fn estimated_remaining(&self) -> Option<Duration> {
+        self.eta?.estimated_remaining()
+    }
+}
@@ -0,0 +1,100 @@
+use bevy_ecs::prelude::*;
+use crate::Progress;
+
+/// Extension trait for recording the output of a system into a progress tracker.
+pub trait TrackProgressSystemExt<Out, Marker>: IntoSystem<(), Out, Marker> + Sized
+where
+    Out: Into<Contribution> + 'static,
+{
+    /// Pipes the output of the system into the [`Progress<T>`] resource.
+    ///
+    /// The system can return anything that converts into a [`Contribution`],
+    /// such as a `(done, total)` pair, a `bool` for a single unit of work,
+    /// or an `Option` of either, where `None` records nothing.
+    ///
+    /// ```ignore
+    /// fn load_things(/* ... */) -> (u32, u32) {
+    ///     (loaded, expected)
+    /// }
+    ///
+    /// app.add_systems(Update, load_things.track_progress::<Loading>());
+    /// ```
+    ///
+    /// Does nothing if the resource doesn't exist.
+    fn track_progress<T: ?Sized + Send + Sync + 'static>(self) -> impl System<In = (), Out = ()> {
+        self.pipe(track_output_system::<T, Out>)
+    }
+}
+
+impl<S, Out, Marker> TrackProgressSystemExt<Out, Marker> for S
+where
+    S: IntoSystem<(), Out, Marker>,
+    Out: Into<Contribution> + 'static,
+{}
+
+fn track_output_system<T: ?Sized + Send + Sync + 'static, C: Into<Contribution>>(
+    In(contribution): In<C>,
+    resource: Option<ResMut<Progress<T>>>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let contribution = contribution.into();
+    resource.track_weighted(contribution.done, contribution.total, contribution.weight);
+}
+
+/// Work returned by a system, recorded with [`track_progress`](TrackProgressSystemExt::track_progress).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contribution {
+    /// Units of work completed.
+    pub done: u32,
+    /// Units of work in total.
+    pub total: u32,
+    /// The weight of each unit of work, as in [`Progress::track_weighted`].
+    pub weight: f32,
+}
+
+impl Contribution {
+    /// Creates a new [`Contribution`] with a weight of `1.0`.
+    pub fn new(done: u32, total: u32) -> Self {
+        Self { done, total, weight: 1.0 }
+    }
+
+    /// Sets the weight of each unit of work.
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl Default for Contribution {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl From<(u32, u32)> for Contribution {
+    #[inline]
+    fn from((done, total): (u32, u32)) -> Self {
+        Self::new(done, total)
+    }
+}
+
+/// A single unit of work, which is done if `true`.
+impl From<bool> for Contribution {
+    #[inline]
+    fn from(done: bool) -> Self {
+        Self::new(done as u32, 1)
+    }
+}
+
+/// No work is recorded for `None`.
+impl<C: Into<Contribution>> From<Option<C>> for Contribution {
+    #[inline]
+    fn from(value: Option<C>) -> Self {
+        value.map(Into::into).unwrap_or_default()
+    }
+}
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{Progress, ProgressSystems, ProgressTrackingEnabled};
+
+#[cfg(feature="bevy_state")]
+use bevy_state::{prelude::*, state::FreelyMutableState};
+
+type OnComplete = Arc<dyn Fn(&mut World) + Send + Sync>;
+
+/// Creates a [`ProgressPipeline`], starting with the tracker for `T`.
+pub fn pipeline<T: Send + Sync + 'static>() -> ProgressPipeline {
+    ProgressPipeline::new().then::<T>()
+}
+
+/// Runs the [`Progress`] resources of several types one after another, for multi-phase loading.
+///
+/// Only the tracker of the current stage is enabled, using [`ProgressTrackingEnabled`].
+/// Once it has recorded work and completed all of it, it is disabled and the tracker of the next stage is enabled.
+/// Stages that stay empty never complete, so each stage must record some work.
+/// When the last stage completes, [`PipelineComplete`] is raised.
+/// The overall progress can be read from the [`ProgressPipelineState`] resource.
+///
+/// Each stage requires progress tracking for its type to be added as a resource,
+/// such as with [`ResourceProgressTrackingPlugin`](crate::ResourceProgressTrackingPlugin).
+/// Only one pipeline can be added to an app.
+pub struct ProgressPipeline {
+    /// The schedule in which stages are advanced.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    stages: Vec<PipelineStage>,
+    on_complete: Option<OnComplete>,
+}
+
+impl ProgressPipeline {
+    /// Creates a new [`ProgressPipeline`] with no stages.
+    pub fn new() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            stages: Vec::new(),
+            on_complete: None,
+        }
+    }
+
+    /// Adds the tracker for `T` as the next stage.
+    pub fn then<T: Send + Sync + 'static>(mut self) -> Self {
+        self.stages.push(PipelineStage::new::<T>());
+        self
+    }
+
+    /// Runs `function` when the last stage completes.
+    pub fn on_complete_with(mut self, function: impl Fn(&mut World) + Send + Sync + 'static) -> Self {
+        self.on_complete = Some(Arc::new(function));
+        self
+    }
+
+    /// Transitions to `state` when the last stage completes.
+    #[cfg(feature="bevy_state")]
+    pub fn on_complete<S: FreelyMutableState>(self, state: S) -> Self {
+        self.on_complete_with(move |world| {
+            world.resource_mut::<NextState<S>>().set(state.clone());
+        })
+    }
+}
+
+impl Default for ProgressPipeline {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for ProgressPipeline {
+    fn build(&self, app: &mut App) {
+        for (index, stage) in self.stages.iter().enumerate() {
+            (stage.init)(app.world_mut(), index == 0);
+        }
+
+        app.insert_resource(PipelineStages {
+            stages: self.stages.clone().into(),
+            on_complete: self.on_complete.clone(),
+        });
+
+        app.insert_resource(ProgressPipelineState {
+            stage: 0,
+            stages: self.stages.len(),
+            fract: 0.0,
+        });
+
+        app.add_systems(self.schedule, pipeline_system
+            .after(ProgressSystems::Check));
+    }
+}
+
+#[derive(Clone)]
+struct PipelineStage {
+    init: fn(&mut World, bool),
+    enable: fn(&mut World, bool),
+    done: fn(&World) -> bool,
+    fract: fn(&World) -> f32,
+}
+
+impl PipelineStage {
+    fn new<T: Send + Sync + 'static>() -> Self {
+        Self {
+            init: |world, enabled| {
+                world.init_resource::<Progress<T>>();
+                world.insert_resource(ProgressTrackingEnabled::<T>::new(enabled));
+            },
+
+            enable: |world, enabled| {
+                world.insert_resource(ProgressTrackingEnabled::<T>::new(enabled));
+            },
+
+            done: |world| world.get_resource::<Progress<T>>().is_some_and(|v| v.has_completed()),
+            fract: |world| world.get_resource::<Progress<T>>().map_or(0.0, |v| v.previous_fract),
+        }
+    }
+}
+
+#[derive(Resource)]
+struct PipelineStages {
+    stages: Arc<[PipelineStage]>,
+    on_complete: Option<OnComplete>,
+}
+
+/// The overall progress of the [`ProgressPipeline`].
+#[derive(Debug, Resource)]
+pub struct ProgressPipelineState {
+    stage: usize,
+    stages: usize,
+    fract: f32,
+}
+
+impl ProgressPipelineState {
+    /// Returns the index of the current stage, and the total number of stages.
+    ///
+    /// Once all stages are complete, the index is equal to the number of stages.
+    #[inline]
+    pub fn stage(&self) -> (usize, usize) {
+        (self.stage, self.stages)
+    }
+
+    /// Returns the progress of the whole pipeline as a fraction,
+    /// where each stage makes up an equal part.
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns `true` if all stages are complete.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.stage >= self.stages
+    }
+}
+
+/// An observer event raised when the last stage of the [`ProgressPipeline`] completes.
+#[derive(Event)]
+pub struct PipelineComplete;
+
+fn pipeline_system(world: &mut World) {
+    let progress = world.resource::<ProgressPipelineState>();
+    if progress.is_done() { return }
+    let index = progress.stage;
+
+    let stages = world.resource::<PipelineStages>().stages.clone();
+    let stage = &stages[index];
+
+    if !(stage.done)(world) {
+        let fract = (index as f32 + (stage.fract)(world)) / stages.len() as f32;
+        let mut progress = world.resource_mut::<ProgressPipelineState>();
+        if progress.fract != fract { progress.fract = fract }
+        return;
+    }
+
+    (stage.enable)(world, false);
+    let index = index + 1;
+
+    let mut progress = world.resource_mut::<ProgressPipelineState>();
+    progress.stage = index;
+    progress.fract = index as f32 / stages.len() as f32;
+
+    if let Some(next) = stages.get(index) {
+        (next.enable)(world, true);
+        return;
+    }
+
+    if let Some(on_complete) = world.resource::<PipelineStages>().on_complete.clone() {
+        on_complete(world);
+    }
+
+    world.trigger(PipelineComplete);
+}
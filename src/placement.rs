@@ -0,0 +1,63 @@
+use std::sync::Arc;
+use bevy_ecs::{prelude::*, schedule::{BoxedCondition, InternedSystemSet, SystemConfigs}};
+
+/// User-provided configuration for the systems added by a tracking plugin.
+#[derive(Clone, Default)]
+pub(crate) struct SystemPlacement {
+    conditions: Vec<Arc<dyn Fn() -> BoxedCondition + Send + Sync>>,
+    sets: Vec<InternedSystemSet>,
+    before: Vec<InternedSystemSet>,
+    after: Vec<InternedSystemSet>,
+}
+
+impl SystemPlacement {
+    pub(crate) fn run_if<M>(&mut self, condition: impl Condition<M> + Clone + Send + Sync + 'static) {
+        // Conditions are stored as factories, since plugins are only borrowed when built.
+        self.conditions.push(Arc::new(move || Box::new(IntoSystem::into_system(condition.clone()))));
+    }
+
+    pub(crate) fn in_set(&mut self, set: impl SystemSet) {
+        self.sets.push(set.intern());
+    }
+
+    pub(crate) fn before<M>(&mut self, set: impl IntoSystemSet<M>) {
+        self.before.push(set.into_system_set().intern());
+    }
+
+    pub(crate) fn after<M>(&mut self, set: impl IntoSystemSet<M>) {
+        self.after.push(set.into_system_set().intern());
+    }
+
+    /// Returns the conditions, for systems that are run without a schedule.
+    pub(crate) fn conditions(&self) -> impl Iterator<Item = BoxedCondition> + '_ {
+        self.conditions.iter().map(|condition| condition())
+    }
+
+    /// Applies conditions and sets, for all systems added by the plugin.
+    pub(crate) fn apply(&self, mut configs: SystemConfigs) -> SystemConfigs {
+        for condition in &self.conditions {
+            configs.run_if_dyn(condition());
+        }
+
+        for set in &self.sets {
+            configs = configs.in_set(*set);
+        }
+
+        configs
+    }
+
+    /// Applies conditions, sets, and ordering constraints, for the systems that check trackers.
+    pub(crate) fn apply_ordered(&self, configs: SystemConfigs) -> SystemConfigs {
+        let mut configs = self.apply(configs);
+
+        for set in &self.before {
+            configs = configs.before(*set);
+        }
+
+        for set in &self.after {
+            configs = configs.after(*set);
+        }
+
+        configs
+    }
+}
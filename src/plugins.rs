@@ -0,0 +1,131 @@
+use bevy_app::{prelude::*, PluginGroupBuilder};
+use bevy_ecs::schedule::{ScheduleLabel, InternedScheduleLabel};
+use crate::{ProgressTrackingPlugin, ResetPolicy};
+
+/// Adds [`ProgressTrackingPlugin`] for many types at once, with shared configuration.
+///
+/// ```ignore
+/// app.add_plugins(ProgressTrackingPlugins::new()
+///     .track::<AssetsLoading>()
+///     .track::<WorldGeneration>()
+///     .track::<ShaderWarmup>()
+///     .without_entities());
+/// ```
+pub struct ProgressTrackingPlugins {
+    /// The schedule in which the progress value is checked.
+    pub check_schedule: InternedScheduleLabel,
+
+    /// The schedule in which the progress value is reset.
+    /// This should be the same as, or after, `check_schedule`.
+    pub reset_schedule: InternedScheduleLabel,
+
+    /// Whether to track progress as a resource.
+    pub resource: bool,
+
+    /// Whether to track progress as a component.
+    pub entities: bool,
+
+    /// Whether to also write [`Done`](crate::Done) into an [`Events`](bevy_ecs::event::Events) buffer.
+    pub buffered_events: bool,
+
+    /// When trackers are reset.
+    pub reset_policy: ResetPolicy,
+
+    /// Whether to run the systems for all types from shared systems.
+    /// See [`ProgressTrackingPlugin::consolidated`] for details.
+    pub consolidated: bool,
+
+    types: Vec<fn(&Self, PluginGroupBuilder) -> PluginGroupBuilder>,
+}
+
+impl ProgressTrackingPlugins {
+    /// Creates a new [`ProgressTrackingPlugins`] with no types.
+    pub fn new() -> Self {
+        Self {
+            check_schedule: PostUpdate.intern(),
+            reset_schedule: Last.intern(),
+            resource: true,
+            entities: true,
+            buffered_events: false,
+            reset_policy: ResetPolicy::EveryTick,
+            consolidated: false,
+            types: Vec::new(),
+        }
+    }
+
+    /// Adds progress tracking for `T`.
+    pub fn track<T: Send + Sync + 'static>(mut self) -> Self {
+        self.types.push(|config, group| group.add(ProgressTrackingPlugin::<T> {
+            check_schedule: config.check_schedule,
+            reset_schedule: config.reset_schedule,
+            resource: config.resource,
+            entities: config.entities,
+            buffered_events: config.buffered_events,
+            reset_policy: config.reset_policy,
+            consolidated: config.consolidated,
+            ..Default::default()
+        }));
+
+        self
+    }
+
+    /// Checks trackers in [`FixedPostUpdate`] and resets them in [`FixedLast`] for all types.
+    ///
+    /// See [`ProgressTrackingPlugin`] for details.
+    pub fn in_fixed_timestep(mut self) -> Self {
+        self.check_schedule = FixedPostUpdate.intern();
+        self.reset_schedule = FixedLast.intern();
+        self
+    }
+
+    /// Runs the systems for all types from shared systems, instead of adding separate systems for each type.
+    ///
+    /// See [`ProgressTrackingPlugin::consolidated`] for details.
+    pub fn consolidated(mut self) -> Self {
+        self.consolidated = true;
+        self
+    }
+
+    /// Disables tracking progress as a resource for all types.
+    pub fn without_resource(mut self) -> Self {
+        self.resource = false;
+        self
+    }
+
+    /// Disables tracking progress as a component for all types.
+    pub fn without_entities(mut self) -> Self {
+        self.entities = false;
+        self
+    }
+
+    /// Enables writing [`Done`](crate::Done) into an [`Events`](bevy_ecs::event::Events) buffer for all types.
+    pub fn with_buffered_events(mut self) -> Self {
+        self.buffered_events = true;
+        self
+    }
+
+    /// Sets when trackers are reset for all types.
+    pub fn with_reset_policy(mut self, policy: ResetPolicy) -> Self {
+        self.reset_policy = policy;
+        self
+    }
+}
+
+impl Default for ProgressTrackingPlugins {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PluginGroup for ProgressTrackingPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let mut group = PluginGroupBuilder::start::<Self>();
+
+        for add in &self.types {
+            group = add(&self, group);
+        }
+
+        group
+    }
+}
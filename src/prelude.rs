@@ -0,0 +1,103 @@
+//! Commonly used items, for glob importing.
+//!
+//! ```
+//! use bevy_mod_progress::prelude::*;
+//! ```
+
+pub use crate::{
+    AppProgressExt,
+    AppTrackedSystemsExt,
+    AutoProgressPlugin,
+    Cancelled,
+    CombinedProgressPlugin,
+    Done,
+    DumpProgress,
+    DynamicProgressPlugin,
+    EntityProgressTrackingPlugin,
+    EtaPlugin,
+    EventCountPlugin,
+    GroupReadinessPlugin,
+    MilestonePlugin,
+    Progress,
+    ProgressChannelPlugin,
+    ProgressCommandsExt,
+    ProgressEntityCommandsExt,
+    ProgressFuturePlugin,
+    ProgressLogPlugin,
+    ProgressPaused,
+    ProgressPipeline,
+    ProgressStagesPlugin,
+    ProgressSystems,
+    ProgressTaskPlugin,
+    ProgressTimeoutPlugin,
+    ProgressTrackingEnabled,
+    ProgressTrackingPlugin,
+    ProgressTrackingPlugins,
+    QueryProgressPlugin,
+    ResetProgress,
+    ResourceProgressTrackingPlugin,
+    RetryPlugin,
+    SharedProgressPlugin,
+    SmoothedProgressPlugin,
+    Started,
+    SubAppProgressPlugin,
+    TaskGraphPlugin,
+    TrackProgressSystemExt,
+    TrackWork,
+    TrackedTaskPlugin,
+    Updated,
+    WorkQueuePlugin,
+    WorldProgressExt,
+    pipeline,
+    progress_at_least,
+    progress_done,
+    progress_not_done,
+};
+
+#[cfg(feature="bevy_asset")]
+pub use crate::AssetProgressPlugin;
+
+#[cfg(feature="bevy_diagnostic")]
+pub use crate::ProgressDiagnosticsPlugin;
+
+#[cfg(feature="bevy_egui")]
+pub use crate::ProgressInspectorPlugin;
+
+#[cfg(feature="bevy_hierarchy")]
+pub use crate::HierarchicalProgressPlugin;
+
+#[cfg(feature="bevy_render")]
+pub use crate::{ExtractProgressPlugin, PipelineProgressPlugin};
+
+#[cfg(feature="bevy_scene")]
+pub use crate::SceneProgressPlugin;
+
+#[cfg(feature="bevy_state")]
+pub use crate::{StateScopedProgressPlugin, TransitionOnDone};
+
+#[cfg(feature="bevy_text")]
+pub use crate::ProgressTextPlugin;
+
+#[cfg(feature="bevy_time")]
+pub use crate::TimedWorkPlugin;
+
+#[cfg(feature="bevy_ui")]
+pub use crate::ProgressBarPlugin;
+
+#[cfg(feature="derive")]
+pub use crate::ProgressMarker;
+
+#[cfg(feature="http")]
+pub use crate::ProgressStatusPlugin;
+
+#[cfg(feature="indicatif")]
+pub use crate::TerminalProgressPlugin;
+
+#[cfg(feature="prometheus")]
+pub use crate::ProgressMetricsPlugin;
+
+#[cfg(feature="replication")]
+pub use crate::ProgressReplicationPlugin;
+
+#[cfg(feature="replicon")]
+pub use crate::RepliconProgressPlugin;
@@ -0,0 +1,63 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, query::QueryFilter, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Derives progress from the world, by counting entities that match query filters.
+///
+/// Each tick, every entity matching `Q` is recorded as one unit of work into the [`Progress<T>`] resource,
+/// which is done if the entity also matches `F`. For example, `QueryProgressPlugin<T, With<Mesh>, With<MeshReady>>`
+/// tracks how many meshes are ready, out of all meshes.
+pub struct QueryProgressPlugin<T: ?Sized, Q, F> {
+    /// The schedule in which entities are counted.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+    _p2: PhantomData<fn() -> (Q, F)>,
+}
+
+impl<T: ?Sized, Q, F> Default for QueryProgressPlugin<T, Q, F> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+            _p2: PhantomData,
+        }
+    }
+}
+
+impl<T, Q, F> Plugin for QueryProgressPlugin<T, Q, F>
+where
+    T: Send + Sync + 'static,
+    Q: QueryFilter + 'static,
+    F: QueryFilter + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, query_progress_system::<T, Q, F>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn query_progress_system<T, Q, F>(
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+    total: Query<(), Q>,
+    done: Query<(), (Q, F)>,
+) where
+    T: ?Sized + Send + Sync + 'static,
+    Q: QueryFilter + 'static,
+    F: QueryFilter + 'static,
+{
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        progress.track_usize(done.iter().count(), total.iter().count());
+    });
+}
@@ -0,0 +1,157 @@
+use std::{collections::VecDeque, marker::PhantomData};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}, world::Command};
+use bevy_utils::{synccell::SyncCell, Duration, Instant};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+type Job = Box<dyn FnOnce(&mut World) + Send>;
+
+/// Runs jobs from the [`WorkQueue<T>`] resource under a per-frame time budget,
+/// recording them as work in the [`Progress<T>`] resource.
+///
+/// Like the tracking systems, jobs aren't run while tracking is disabled with
+/// [`ProgressTrackingEnabled<T>`](crate::ProgressTrackingEnabled), or paused with [`ProgressPaused<T>`].
+pub struct WorkQueuePlugin<T: ?Sized> {
+    /// The schedule in which jobs are run.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// How much time can be spent running jobs each frame.
+    ///
+    /// At least one job is run each frame, even if it takes longer than the budget.
+    pub budget: Duration,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for WorkQueuePlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            budget: Duration::from_millis(2),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> WorkQueuePlugin<T> {
+    /// Sets how much time can be spent running jobs each frame.
+    pub fn with_budget(mut self, budget: Duration) -> Self {
+        self.budget = budget;
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for WorkQueuePlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WorkQueue<T>>();
+        app.insert_resource(QueueBudget::<T> {
+            budget: self.budget,
+            _p1: PhantomData,
+        });
+
+        app.add_systems(self.schedule, work_queue_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+#[derive(Resource)]
+struct QueueBudget<T: ?Sized> {
+    budget: Duration,
+    _p1: PhantomData<T>,
+}
+
+fn work_queue_system<T: ?Sized + Send + Sync + 'static>(world: &mut World) {
+    let budget = world.resource::<QueueBudget<T>>().budget;
+    let start = Instant::now();
+
+    // Jobs are taken one at a time, so they can push more jobs to the queue
+    loop {
+        let mut queue = world.resource_mut::<WorkQueue<T>>();
+        let job = match queue.jobs.get().pop_front() {
+            Some(v) => v,
+            None => break,
+        };
+
+        job(world);
+        world.resource_mut::<WorkQueue<T>>().done += 1;
+
+        if start.elapsed() >= budget { break }
+    }
+
+    let policy = world.get_resource::<ResetPolicies<T>>().and_then(|v| v.resource);
+    world.resource_scope(|world, mut queue: Mut<WorkQueue<T>>| {
+        let (done, total) = queue.work();
+
+        if let Some(mut resource) = world.get_resource_mut::<Progress<T>>() {
+            record_full_state(policy, &mut resource, &mut queue.synced, |progress| {
+                progress.track_u64(done, total);
+            });
+        }
+    });
+}
+
+/// A queue of jobs that need exclusive access to the [`World`], run over several frames by [`WorkQueuePlugin<T>`].
+///
+/// Each job counts as one unit of work in the [`Progress<T>`] resource.
+/// Once all jobs have been run, the count starts over when another job is pushed.
+#[derive(Resource)]
+pub struct WorkQueue<T: ?Sized> {
+    jobs: SyncCell<VecDeque<Job>>,
+    done: u64,
+    total: u64,
+    synced: SyncedWork,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> WorkQueue<T> {
+    /// Creates a new, empty [`WorkQueue`].
+    pub fn new() -> Self {
+        Self {
+            jobs: SyncCell::new(VecDeque::new()),
+            done: 0,
+            total: 0,
+            synced: SyncedWork::default(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Adds a job to the end of the queue.
+    pub fn push(&mut self, job: impl FnOnce(&mut World) + Send + 'static) {
+        if self.is_empty() {
+            self.done = 0;
+            self.total = 0;
+            self.synced = SyncedWork::default();
+        }
+
+        self.jobs.get().push_back(Box::new(job));
+        self.total += 1;
+    }
+
+    /// Adds a [`Command`] to the end of the queue, to be applied as a job.
+    pub fn push_command(&mut self, command: impl Command) {
+        self.push(move |world| command.apply(world));
+    }
+
+    /// Returns the number of jobs that have been run and the total number of jobs pushed,
+    /// since the queue was last empty.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns `true` if all jobs have been run.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.done >= self.total
+    }
+}
+
+impl<T: ?Sized> Default for WorkQueue<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
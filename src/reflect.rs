@@ -0,0 +1,53 @@
+use bevy_app::prelude::*;
+use bevy_reflect::{utility::GenericTypePathCell, TypePath};
+use crate::{Done, EmptyFract, OverflowPolicy, Progress, ProgressSnapshot, ResetPolicy};
+
+/// Registers the reflected types for `T` in the app's type registry.
+pub(crate) fn register_types<T: Send + Sync + 'static>(app: &mut App) {
+    app.register_type::<Progress<T>>();
+    app.register_type::<Done<T>>();
+    app.register_type::<ProgressSnapshot<T>>();
+    app.register_type::<ResetPolicy>();
+    app.register_type::<OverflowPolicy>();
+    app.register_type::<EmptyFract>();
+}
+
+// Type paths are implemented manually, since the derived implementation
+// requires `T` to implement `TypePath`, which marker types usually don't.
+macro_rules! impl_type_path {
+    ($name:ident) => {
+        impl<T: ?Sized + 'static> TypePath for $name<T> {
+            fn type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| format!(
+                    concat!(env!("CARGO_CRATE_NAME"), "::", stringify!($name), "<{}>"),
+                    std::any::type_name::<T>(),
+                ))
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: GenericTypePathCell = GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| format!(
+                    concat!(stringify!($name), "<{}>"),
+                    bevy_utils::get_short_name(std::any::type_name::<T>()),
+                ))
+            }
+
+            fn type_ident() -> Option<&'static str> {
+                Some(stringify!($name))
+            }
+
+            fn crate_name() -> Option<&'static str> {
+                Some(env!("CARGO_CRATE_NAME"))
+            }
+
+            fn module_path() -> Option<&'static str> {
+                Some(env!("CARGO_CRATE_NAME"))
+            }
+        }
+    };
+}
+
+impl_type_path!(Progress);
+impl_type_path!(Done);
+impl_type_path!(ProgressSnapshot);
@@ -0,0 +1,299 @@
+use std::{any::TypeId, fmt, marker::PhantomData, sync::{Mutex, PoisonError}};
+use bevy_utils::Duration;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, query::QueryState};
+use crate::{Done, EtaEstimator, Progress, ProgressStages, SparseProgress};
+
+/// A list of all types with progress tracking, populated by the tracking plugins.
+///
+/// This allows tools to enumerate and read trackers at runtime, without knowing their types.
+#[derive(Resource, Default)]
+pub struct ProgressRegistry {
+    entries: Vec<RegisteredProgress>,
+}
+
+impl ProgressRegistry {
+    /// Returns an iterator over all registered types.
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredProgress> {
+        self.entries.iter()
+    }
+
+    /// Returns the entry for `T`, if it has been registered.
+    pub fn get<T: ?Sized + 'static>(&self) -> Option<&RegisteredProgress> {
+        self.get_by_id(TypeId::of::<T>())
+    }
+
+    /// Returns the entry for the type with the given [`TypeId`], if it has been registered.
+    pub fn get_by_id(&self, id: TypeId) -> Option<&RegisteredProgress> {
+        self.entries.iter().find(|entry| entry.type_id == id)
+    }
+}
+
+/// Registers `T` as tracked as a resource or as a component.
+///
+/// Returns an error if it is already tracked that way, leaving the registry unchanged,
+/// since the check and reset systems would otherwise run twice, raising every event twice.
+pub(crate) fn register<T: ?Sized + Send + Sync + 'static>(app: &mut App, resource: bool) -> Result<(), AlreadyTracked> {
+    if !app.world().contains_resource::<ProgressRegistry>() {
+        crate::dump::setup(app);
+    }
+
+    if !app.world().contains_resource::<TrackerQuery<T>>() {
+        let query = TrackerQuery::<T>::new(app.world_mut());
+        app.insert_resource(query);
+    }
+
+    let mut registry = app.world_mut().get_resource_or_insert_with(ProgressRegistry::default);
+
+    let index = match registry.entries.iter().position(|entry| entry.type_id == TypeId::of::<T>()) {
+        Some(v) => v,
+        None => {
+            registry.entries.push(RegisteredProgress {
+                type_id: TypeId::of::<T>(),
+                type_name: std::any::type_name::<T>(),
+                tracks_resource: false,
+                tracks_entities: false,
+                resource: resource_info::<T>,
+                entities: entity_info::<T>,
+                rate: resource_rate::<T>,
+                eta: resource_eta::<T>,
+                stage: resource_stage::<T>,
+                completions: 0,
+            });
+
+            registry.entries.len() - 1
+        },
+    };
+
+    let entry = &mut registry.entries[index];
+
+    let (tracked, kind) = match resource {
+        true => (&mut entry.tracks_resource, "a resource"),
+        false => (&mut entry.tracks_entities, "a component"),
+    };
+
+    if *tracked {
+        return Err(AlreadyTracked { type_name: entry.type_name, kind });
+    }
+
+    *tracked = true;
+
+    if resource {
+        app.observe(count_completions::<T>);
+    }
+
+    Ok(())
+}
+
+/// Returned when registering a type that is already tracked the same way.
+#[derive(Debug)]
+pub(crate) struct AlreadyTracked {
+    type_name: &'static str,
+    kind: &'static str,
+}
+
+impl fmt::Display for AlreadyTracked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "progress tracking for `{}` as {} was added more than once", self.type_name, self.kind)
+    }
+}
+
+/// Entities with tracker components of `T`, in either storage.
+type Trackers<T> = (Entity, AnyOf<(&'static Progress<T>, &'static SparseProgress<T>)>);
+
+/// A cached query for tracker components of `T`.
+///
+/// Entries only have access to a `&World`, so the state is behind a lock to update its archetypes.
+#[derive(Resource)]
+struct TrackerQuery<T: ?Sized + Send + Sync + 'static> {
+    state: Mutex<QueryState<Trackers<T>>>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> TrackerQuery<T> {
+    fn new(world: &mut World) -> Self {
+        Self {
+            state: Mutex::new(QueryState::new(world)),
+            _p1: PhantomData,
+        }
+    }
+}
+
+fn count_completions<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Done<T>>,
+    progress: Option<Res<Progress<T>>>,
+    mut registry: ResMut<ProgressRegistry>,
+) {
+    // Done is also raised for empty trackers, which haven't completed anything
+    if trigger.event().entity().is_some() { return }
+    if !progress.is_some_and(|v| v.has_completed()) { return }
+
+    if let Some(entry) = registry.entries.iter_mut().find(|entry| entry.type_id == TypeId::of::<T>()) {
+        entry.completions += 1;
+    }
+}
+
+/// A type-erased entry in the [`ProgressRegistry`].
+pub struct RegisteredProgress {
+    type_id: TypeId,
+    type_name: &'static str,
+    tracks_resource: bool,
+    tracks_entities: bool,
+    resource: fn(&World) -> Option<ProgressInfo>,
+    entities: fn(&World) -> Vec<(Entity, ProgressInfo)>,
+    rate: fn(&World) -> Option<f64>,
+    eta: fn(&World) -> Option<Duration>,
+    stage: for<'w> fn(&'w World) -> Option<&'w str>,
+    completions: u64,
+}
+
+impl RegisteredProgress {
+    /// Returns the [`TypeId`] of the tracked type.
+    #[inline]
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the name of the tracked type, as returned by [`std::any::type_name`].
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns `true` if the type is tracked as a resource.
+    #[inline]
+    pub fn tracks_resource(&self) -> bool {
+        self.tracks_resource
+    }
+
+    /// Returns `true` if the type is tracked as a component.
+    #[inline]
+    pub fn tracks_entities(&self) -> bool {
+        self.tracks_entities
+    }
+
+    /// Returns how many times the [`Progress`] resource of the tracked type has completed,
+    /// counted from its [`Done`] events. Empty trackers don't count.
+    #[inline]
+    pub fn completions(&self) -> u64 {
+        self.completions
+    }
+
+    /// Reads the [`Progress`] resource of the tracked type, if it exists.
+    pub fn resource(&self, world: &World) -> Option<ProgressInfo> {
+        (self.resource)(world)
+    }
+
+    /// Reads all [`Progress`] and [`SparseProgress`] components of the tracked type.
+    pub fn entities(&self, world: &World) -> Vec<(Entity, ProgressInfo)> {
+        (self.entities)(world)
+    }
+
+    /// Returns the rate of work for the [`Progress`] resource of the tracked type,
+    /// if an [`EtaEstimator`] exists for it and the rate can be estimated.
+    pub fn rate(&self, world: &World) -> Option<f64> {
+        (self.rate)(world)
+    }
+
+    /// Returns the estimated time until the [`Progress`] resource of the tracked type is done,
+    /// if an [`EtaEstimator`] exists for it and the rate can be estimated.
+    pub fn estimated_remaining(&self, world: &World) -> Option<Duration> {
+        (self.eta)(world)
+    }
+
+    /// Returns the name of the current stage of the [`ProgressStages`] resource of the tracked type, if it exists.
+    pub fn stage<'w>(&self, world: &'w World) -> Option<&'w str> {
+        (self.stage)(world)
+    }
+}
+
+fn resource_info<T: ?Sized + Send + Sync + 'static>(world: &World) -> Option<ProgressInfo> {
+    world.get_resource::<Progress<T>>().map(ProgressInfo::new)
+}
+
+fn entity_info<T: ?Sized + Send + Sync + 'static>(world: &World) -> Vec<(Entity, ProgressInfo)> {
+    let query = match world.get_resource::<TrackerQuery<T>>() {
+        Some(v) => v,
+        None => return Vec::new(),
+    };
+
+    let mut state = query.state.lock().unwrap_or_else(PoisonError::into_inner);
+    state.update_archetypes(world);
+
+    state.iter_manual(world)
+        .filter_map(|(entity, (progress, sparse))| {
+            Some((entity, ProgressInfo::new(progress.or(sparse.map(|v| &**v))?)))
+        })
+        .collect()
+}
+
+fn resource_rate<T: ?Sized + Send + Sync + 'static>(world: &World) -> Option<f64> {
+    world.get_resource::<EtaEstimator<T>>()?.rate()
+}
+
+fn resource_eta<T: ?Sized + Send + Sync + 'static>(world: &World) -> Option<Duration> {
+    world.get_resource::<EtaEstimator<T>>()?.estimated_remaining()
+}
+
+fn resource_stage<T: ?Sized + Send + Sync + 'static>(world: &World) -> Option<&str> {
+    world.get_resource::<ProgressStages<T>>()?.current().map(|(_, name)| name)
+}
+
+/// The state of a [`Progress`] tracker, read through the [`ProgressRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressInfo {
+    done: u64,
+    total: u64,
+    fract: f32,
+    stalled: bool,
+    complete: bool,
+    completed: bool,
+}
+
+impl ProgressInfo {
+    pub(crate) fn new<T: ?Sized>(progress: &Progress<T>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            done,
+            total,
+            fract: progress.fract(),
+            stalled: progress.stalled(),
+            complete: progress.is_done(),
+            completed: progress.has_completed(),
+        }
+    }
+
+    /// Returns the work that has been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the progress as a fraction, as returned by [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns `true` if the tracker is stalled, as returned by [`Progress::stalled`].
+    #[inline]
+    pub fn stalled(&self) -> bool {
+        self.stalled
+    }
+
+    /// Returns `true` if the tracker is done, as returned by [`Progress::is_done`].
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.complete
+    }
+
+    /// Returns `true` if the tracker had work recorded and completed it when it was last checked.
+    ///
+    /// Unlike [`is_done`](Self::is_done), this is `false` for empty trackers,
+    /// and for trackers that haven't been checked since their work completed.
+    #[inline]
+    pub fn has_completed(&self) -> bool {
+        self.completed
+    }
+}
@@ -0,0 +1,120 @@
+use std::{marker::PhantomData, sync::{Arc, Mutex}};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_render::{render_resource::{CachedPipelineState, PipelineCache}, Render, RenderApp, RenderSet};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// A marker type for tracking render pipeline compilation with [`PipelineProgressPlugin`].
+pub enum PipelineWarmup {}
+
+/// Records the number of compiled render pipelines into the [`Progress<T>`] resource.
+///
+/// Each pipeline in the render world's [`PipelineCache`] is one unit of work, which is done once it has been created.
+/// Pipelines that fail to compile are recorded as failed work.
+///
+/// This must be added after `RenderPlugin`, and does nothing if there is no render sub-app.
+pub struct PipelineProgressPlugin<T: ?Sized = PipelineWarmup> {
+    /// The schedule in which pipeline counts are recorded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for PipelineProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for PipelineProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let counts = PipelineCounts::<T>::default();
+
+        let render_app = match app.get_sub_app_mut(RenderApp) {
+            Some(v) => v,
+            None => return,
+        };
+
+        render_app.insert_resource(counts.clone());
+        render_app.add_systems(Render, pipeline_count_system::<T>
+            .in_set(RenderSet::Cleanup));
+
+        app.insert_resource(counts);
+        app.add_systems(self.schedule, pipeline_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+/// Counts shared between the main world and the render world.
+#[derive(Resource)]
+struct PipelineCounts<T: ?Sized> {
+    /// The number of created, total, and failed pipelines.
+    inner: Arc<Mutex<(u64, u64, u64)>>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for PipelineCounts<T> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new((0, 0, 0))),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Clone for PipelineCounts<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+fn pipeline_count_system<T: ?Sized + Send + Sync + 'static>(
+    cache: Option<Res<PipelineCache>>,
+    counts: Res<PipelineCounts<T>>,
+) {
+    let cache = match cache {
+        Some(v) => v,
+        None => return,
+    };
+
+    let (mut done, mut total, mut failed) = (0, 0, 0);
+
+    for pipeline in cache.pipelines() {
+        total += 1;
+        match pipeline.state {
+            CachedPipelineState::Ok(_) => done += 1,
+            CachedPipelineState::Err(_) => failed += 1,
+            CachedPipelineState::Queued | CachedPipelineState::Creating(_) => {},
+        }
+    }
+
+    *counts.inner.lock().unwrap() = (done, total, failed);
+}
+
+fn pipeline_progress_system<T: ?Sized + Send + Sync + 'static>(
+    counts: Res<PipelineCounts<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let (done, total, failed) = *counts.inner.lock().unwrap();
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        progress.record(done, total - failed, 1.0);
+        progress.track_failed(failed as u32);
+    });
+}
@@ -0,0 +1,310 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{entity::{EntityMapper, MapEntities}, prelude::*};
+use bevy_utils::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::{Progress, Updated};
+
+/// Produces and applies progress updates, for replicating trackers over the network.
+///
+/// This is transport-agnostic. Whenever the work recorded by a local [`Progress<T>`] tracker changes,
+/// a [`ProgressUpdate<T>`] is written to [`Events`], which a networking crate can send to other peers.
+/// Updates received from other peers should be written to [`Events`] as [`ReceivedProgressUpdate<T>`],
+/// tagged with the peer that sent them, and are applied to [`RemoteProgress<T>`] components
+/// for updates to entities, and to the [`PeerProgress<T>`] resource for updates to resources.
+/// Since local and received updates are kept apart, local trackers are never applied as remote ones.
+///
+/// Updates implement [`MapEntities`], so they can be mapped to local entities.
+/// For example, each client can send updates for its own [`Progress<T>`] resource,
+/// and the server can assign them to the entity for that player, and forward them to everyone else.
+///
+/// With the `replicon` feature, [`RepliconProgressPlugin<T>`](crate::RepliconProgressPlugin)
+/// sends and receives updates with `bevy_replicon`.
+pub struct ProgressReplicationPlugin<T: ?Sized> {
+    /// Whether to write updates for local trackers.
+    pub send: bool,
+
+    /// Whether to apply received updates to [`RemoteProgress<T>`] and [`PeerProgress<T>`].
+    pub receive: bool,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressReplicationPlugin<T> {
+    fn default() -> Self {
+        Self {
+            send: true,
+            receive: true,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> ProgressReplicationPlugin<T> {
+    /// Only writes updates for local trackers, such as on a client.
+    pub fn send_only(mut self) -> Self {
+        self.receive = false;
+        self
+    }
+
+    /// Only applies received updates, such as on a client that doesn't report its own progress.
+    pub fn receive_only(mut self) -> Self {
+        self.send = false;
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressReplicationPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ProgressUpdate<T>>();
+        app.add_event::<ReceivedProgressUpdate<T>>();
+
+        if self.send {
+            app.observe(replication_send_observer::<T>);
+        }
+
+        if self.receive {
+            app.init_resource::<PeerProgress<T>>();
+            app.add_systems(PreUpdate, replication_receive_system::<T>
+                .in_set(ReplicationReceiveSet));
+        }
+    }
+}
+
+/// The systems that apply received updates, so networking crates can receive them beforehand.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ReplicationReceiveSet;
+
+fn replication_send_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Updated<T>>,
+    mut events: EventWriter<ProgressUpdate<T>>,
+) {
+    let event = trigger.event();
+    let (done, total) = event.work();
+
+    events.send(ProgressUpdate {
+        entity: event.entity(),
+        done,
+        total,
+        fract: event.fract(),
+        _p1: PhantomData,
+    });
+}
+
+fn replication_receive_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut events: EventReader<ReceivedProgressUpdate<T>>,
+    mut peers: ResMut<PeerProgress<T>>,
+    mut query: Query<&mut RemoteProgress<T>>,
+) {
+    for received in events.read() {
+        let remote = RemoteProgress::<T>::from_update(&received.update);
+
+        match received.update.entity {
+            Some(entity) => match query.get_mut(entity) {
+                Ok(mut tracker) => *tracker = remote,
+                Err(_) => if let Some(mut entity) = commands.get_entity(entity) {
+                    entity.try_insert(remote);
+                },
+            },
+
+            None => { peers.peers.insert(received.peer, remote); },
+        }
+    }
+}
+
+/// A compact, serializable update to a [`Progress<T>`] tracker, for sending over the network.
+///
+/// Written and applied by [`ProgressReplicationPlugin<T>`].
+#[derive(Event, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ProgressUpdate<T: ?Sized> {
+    entity: Option<Entity>,
+    done: u64,
+    total: u64,
+    fract: f32,
+    #[serde(skip)]
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressUpdate<T> {
+    /// Creates a [`ProgressUpdate`] from the current state of a tracker.
+    ///
+    /// `entity` should be the entity with the tracker, or `None` if the tracker is a resource.
+    pub fn new(progress: &Progress<T>, entity: Option<Entity>) -> Self {
+        let (done, total) = progress.work();
+
+        Self {
+            entity,
+            done,
+            total,
+            fract: progress.fract(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that had been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the progress as a fraction, as returned by [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns the entity whose tracker was updated, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+
+    /// Sets the entity the update applies to, such as the entity for the player that sent it.
+    pub fn with_entity(mut self, entity: Option<Entity>) -> Self {
+        self.entity = entity;
+        self
+    }
+}
+
+impl<T: ?Sized> Clone for ProgressUpdate<T> {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            done: self.done,
+            total: self.total,
+            fract: self.fract,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> MapEntities for ProgressUpdate<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let Some(entity) = self.entity.as_mut() {
+            *entity = entity_mapper.map_entity(*entity);
+        }
+    }
+}
+
+/// A [`ProgressUpdate<T>`] received from another peer.
+///
+/// Written by the networking layer, and applied by [`ProgressReplicationPlugin<T>`].
+#[derive(Event, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ReceivedProgressUpdate<T: ?Sized> {
+    peer: u64,
+    update: ProgressUpdate<T>,
+}
+
+impl<T: ?Sized> ReceivedProgressUpdate<T> {
+    /// Creates a new [`ReceivedProgressUpdate`] for `update`, sent by `peer`.
+    ///
+    /// `peer` identifies the peer the update came from, such as its client ID,
+    /// so updates to the resources of different peers are kept apart.
+    pub fn new(peer: u64, update: ProgressUpdate<T>) -> Self {
+        Self { peer, update }
+    }
+
+    /// Returns the peer that sent the update.
+    #[inline]
+    pub fn peer(&self) -> u64 {
+        self.peer
+    }
+
+    /// Returns the update.
+    #[inline]
+    pub fn update(&self) -> &ProgressUpdate<T> {
+        &self.update
+    }
+}
+
+impl<T: ?Sized> Clone for ReceivedProgressUpdate<T> {
+    fn clone(&self) -> Self {
+        Self {
+            peer: self.peer,
+            update: self.update.clone(),
+        }
+    }
+}
+
+impl<T: ?Sized> MapEntities for ReceivedProgressUpdate<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.update.map_entities(entity_mapper);
+    }
+}
+
+/// The state of the [`Progress<T>`] resource of each other peer, as of their last update.
+///
+/// Applied by [`ProgressReplicationPlugin<T>`].
+#[derive(Resource)]
+pub struct PeerProgress<T: ?Sized> {
+    peers: HashMap<u64, RemoteProgress<T>>,
+}
+
+impl<T: ?Sized> PeerProgress<T> {
+    /// Returns the state of the resource of `peer`, if it has sent any updates.
+    pub fn get(&self, peer: u64) -> Option<&RemoteProgress<T>> {
+        self.peers.get(&peer)
+    }
+
+    /// Returns an iterator over all peers that have sent updates, and the state of their resources.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &RemoteProgress<T>)> {
+        self.peers.iter().map(|(peer, remote)| (*peer, remote))
+    }
+
+    /// Forgets the state of the resource of `peer`, such as when it disconnects.
+    pub fn remove(&mut self, peer: u64) -> Option<RemoteProgress<T>> {
+        self.peers.remove(&peer)
+    }
+}
+
+impl<T: ?Sized> Default for PeerProgress<T> {
+    fn default() -> Self {
+        Self {
+            peers: HashMap::default(),
+        }
+    }
+}
+
+/// The state of a [`Progress<T>`] tracker on another peer, as of the last update to it.
+///
+/// Inserted as a component for updates to entities, and stored in [`PeerProgress<T>`] for updates to resources.
+/// Applied by [`ProgressReplicationPlugin<T>`].
+#[derive(Component)]
+pub struct RemoteProgress<T: ?Sized> {
+    done: u64,
+    total: u64,
+    fract: f32,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> RemoteProgress<T> {
+    fn from_update(update: &ProgressUpdate<T>) -> Self {
+        Self {
+            done: update.done,
+            total: update.total,
+            fract: update.fract,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work that has been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns the progress as a fraction, as returned by [`Progress::fract`].
+    #[inline]
+    pub fn fract(&self) -> f32 {
+        self.fract
+    }
+
+    /// Returns `true` if all work has been completed.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.done >= self.total
+    }
+}
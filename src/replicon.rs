@@ -0,0 +1,77 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_replicon::prelude::*;
+use crate::{replication::ReplicationReceiveSet, ProgressReplicationPlugin, ProgressUpdate, ReceivedProgressUpdate};
+
+/// Replicates trackers for `T` with `bevy_replicon`, using [`ProgressReplicationPlugin<T>`].
+///
+/// [`ProgressUpdate<T>`] is registered as a client event, and [`ReceivedProgressUpdate<T>`] as a server event.
+/// The server forwards each update it receives to every other client, tagged with the
+/// [`ClientId`] of the client that sent it, and applies it locally.
+/// Updates from the server's own trackers are sent to every client, tagged with [`ClientId::SERVER`].
+/// Peers never receive their own updates.
+///
+/// Entities in updates are mapped between the client and the server,
+/// so updates to entity trackers should only be sent for replicated entities.
+///
+/// This must be added after `RepliconPlugins`. It adds [`ProgressReplicationPlugin<T>`]
+/// with the default settings, unless it has already been added with other settings.
+pub struct RepliconProgressPlugin<T: ?Sized> {
+    /// The channel updates are sent over.
+    /// Defaults to [`ChannelKind::Ordered`].
+    pub channel: ChannelKind,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for RepliconProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            channel: ChannelKind::Ordered,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> RepliconProgressPlugin<T> {
+    /// Sends updates over `channel`.
+    pub fn with_channel(mut self, channel: ChannelKind) -> Self {
+        self.channel = channel;
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for RepliconProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ProgressReplicationPlugin<T>>() {
+            app.add_plugins(ProgressReplicationPlugin::<T>::default());
+        }
+
+        app.add_mapped_client_event::<ProgressUpdate<T>>(self.channel);
+        app.add_mapped_server_event::<ReceivedProgressUpdate<T>>(self.channel);
+
+        app.configure_sets(PreUpdate, ReplicationReceiveSet
+            .after(ClientSet::Receive)
+            .after(ServerSet::Receive));
+
+        app.add_systems(PreUpdate, replicon_forward_system::<T>
+            .after(ServerSet::Receive)
+            .before(ReplicationReceiveSet)
+            .run_if(server_running));
+    }
+}
+
+fn replicon_forward_system<T: ?Sized + Send + Sync + 'static>(
+    mut received: EventReader<FromClient<ProgressUpdate<T>>>,
+    mut forwarded: EventWriter<ToClients<ReceivedProgressUpdate<T>>>,
+) {
+    for FromClient { client_id, event } in received.read() {
+        // Sending to everyone but the sender also applies updates from clients on the server,
+        // but not the server's own updates, which it sends as ClientId::SERVER
+        forwarded.send(ToClients {
+            mode: SendMode::BroadcastExcept(*client_id),
+            event: ReceivedProgressUpdate::new(client_id.get(), event.clone()),
+        });
+    }
+}
@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use crate::{Progress, ResetPolicy, storage::TrackerComponent};
+
+/// An event that can be triggered to reset a progress tracker.
+///
+/// Trigger this without a target to reset the [`Progress<T>`] resource,
+/// or targeted at entities to reset their [`Progress<T>`] components.
+/// The reset happens in [`ProgressSystems::Reset`](crate::ProgressSystems::Reset),
+/// regardless of the [`ResetPolicy`](crate::ResetPolicy) of the tracking plugin.
+/// The tracker's completion cycle is also cleared, so [`Started`](crate::Started)
+/// and [`Done`](crate::Done) are raised again for latched trackers.
+///
+/// See also [`ProgressCommandsExt::reset_progress`](crate::ProgressCommandsExt::reset_progress)
+/// and [`ProgressEntityCommandsExt::reset_progress`](crate::ProgressEntityCommandsExt::reset_progress).
+#[derive(Event)]
+pub struct ResetProgress<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> ResetProgress<T> {
+    /// Creates a new [`ResetProgress`] event.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for ResetProgress<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The [`ResetPolicy`] of the tracking plugins for `T`, or `None` if they haven't been added.
+#[derive(Resource)]
+pub(crate) struct ResetPolicies<T: ?Sized> {
+    pub resource: Option<ResetPolicy>,
+    pub entities: Option<ResetPolicy>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ResetPolicies<T> {
+    fn default() -> Self {
+        Self {
+            resource: None,
+            entities: None,
+            _p1: PhantomData,
+        }
+    }
+}
+
+/// The work a source last recorded into a tracker with [`record_full_state`].
+#[derive(Default)]
+pub(crate) struct SyncedWork {
+    work: (u64, u64),
+    weighted: (f64, f64),
+    float: (f64, f64),
+    hidden: (u64, u64),
+    skipped: u64,
+    failed: u64,
+    reasons: usize,
+}
+
+impl SyncedWork {
+    fn of<T: ?Sized>(progress: &Progress<T>) -> Self {
+        Self {
+            work: (progress.done, progress.total),
+            weighted: (progress.weighted_done, progress.weighted_total),
+            float: (progress.float_done, progress.float_total),
+            hidden: (progress.hidden_done, progress.hidden_total),
+            skipped: progress.skipped,
+            failed: progress.failure.failed,
+            reasons: progress.failure.reasons.len(),
+        }
+    }
+}
+
+/// Records work from a source that records its full state each time, such as a count of loaded assets,
+/// into `tracker`, where `policy` is the [`ResetPolicy`] of the tracker, if it's tracked,
+/// and `synced` is the work recorded from the source last time.
+///
+/// Trackers reset every tick need the full state recorded again, but other trackers keep it,
+/// so only the change since it was last recorded is applied to them, which can also remove work.
+pub(crate) fn record_full_state<T: ?Sized>(
+    policy: Option<ResetPolicy>,
+    tracker: &mut Progress<T>,
+    synced: &mut SyncedWork,
+    record: impl FnOnce(&mut Progress<T>),
+) {
+    if policy.unwrap_or_default() == ResetPolicy::EveryTick {
+        record(tracker);
+        return;
+    }
+
+    let mut state = Progress::<T>::new();
+    record(&mut state);
+
+    let current = SyncedWork::of(&state);
+    let overflow = tracker.overflow;
+    let shift = |value: u64, from: u64, to: u64| match to.checked_sub(from) {
+        Some(added) => overflow.add(value, added),
+        None => value.saturating_sub(from - to),
+    };
+
+    tracker.done = shift(tracker.done, synced.work.0, current.work.0);
+    tracker.total = shift(tracker.total, synced.work.1, current.work.1);
+    tracker.weighted_done = (tracker.weighted_done + current.weighted.0 - synced.weighted.0).max(0.0);
+    tracker.weighted_total = (tracker.weighted_total + current.weighted.1 - synced.weighted.1).max(0.0);
+    tracker.float_done = (tracker.float_done + current.float.0 - synced.float.0).max(0.0);
+    tracker.float_total = (tracker.float_total + current.float.1 - synced.float.1).max(0.0);
+    tracker.hidden_done = shift(tracker.hidden_done, synced.hidden.0, current.hidden.0);
+    tracker.hidden_total = shift(tracker.hidden_total, synced.hidden.1, current.hidden.1);
+    tracker.skipped = shift(tracker.skipped, synced.skipped, current.skipped);
+    tracker.failure.failed = shift(tracker.failure.failed, synced.failed, current.failed);
+
+    // Only reasons for failures added since then are new
+    let reasons = state.failure.reasons.len().min(synced.reasons);
+    tracker.failure.reasons.extend(state.failure.reasons.drain(reasons..));
+
+    *synced = current;
+}
+
+pub(crate) fn resource_reset_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<ResetProgress<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+) {
+    // Targeted resets are for entity trackers
+    if trigger.entity() != Entity::PLACEHOLDER { return }
+
+    if let Some(mut resource) = resource {
+        resource.reset_requested = true;
+    }
+}
+
+pub(crate) fn entity_reset_observer<T: ?Sized + Send + Sync + 'static, C: TrackerComponent<T>>(
+    trigger: Trigger<ResetProgress<T>>,
+    mut query: Query<&mut C>,
+) {
+    let entity = trigger.entity();
+    if entity == Entity::PLACEHOLDER { return }
+
+    if let Ok(mut tracker) = query.get_mut(entity) {
+        tracker.tracker_mut().reset_requested = true;
+    }
+}
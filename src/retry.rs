@@ -0,0 +1,283 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_utils::{Duration, Instant};
+use crate::{Done, Failed, Progress, ProgressPaused, ProgressSystems};
+
+/// Retries trackers with a [`RetryPolicy<T>`] when [`Failed<T>`] is raised.
+///
+/// When a tracker fails, its failed work is removed after the policy's backoff,
+/// and [`RetryAttempt<T>`] is raised so the work can be attempted again.
+/// This happens after the tracker is checked, so the work can be recorded again before the next check.
+/// Once the policy's attempts have run out, [`RetryExhausted<T>`] is raised instead.
+pub struct RetryPlugin<T: ?Sized> {
+    /// The schedule in which retries are started.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for RetryPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for RetryPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.observe(retry_failed_observer::<T>);
+        app.observe(retry_done_observer::<T>);
+
+        app.add_systems(self.schedule, (
+            resource_retry_system::<T>,
+            entity_retry_system::<T>,
+        ).after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+fn retry_failed_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Failed<T>>,
+    mut commands: Commands,
+    resource: Option<ResMut<RetryPolicy<T>>>,
+    mut query: Query<&mut RetryPolicy<T>>,
+) {
+    let event = trigger.event();
+    let entity = event.entity();
+
+    let mut policy = match entity {
+        Some(entity) => match query.get_mut(entity) {
+            Ok(v) => v,
+            Err(_) => return,
+        },
+
+        None => match resource {
+            Some(v) => v.into(),
+            None => return,
+        },
+    };
+
+    // A retry is already waiting to start
+    if policy.pending.is_some() { return }
+
+    if policy.attempts >= policy.max_attempts {
+        let event = RetryExhausted::<T> {
+            attempts: policy.attempts,
+            failed: event.failed(),
+            entity,
+            _p1: PhantomData,
+        };
+
+        match entity {
+            Some(entity) => commands.trigger_targets(event, entity),
+            None => commands.trigger(event),
+        }
+
+        return;
+    }
+
+    let backoff = policy.next_backoff();
+    policy.attempts += 1;
+    policy.pending = Some((Instant::now(), backoff));
+}
+
+fn retry_done_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Done<T>>,
+    resource: Option<ResMut<RetryPolicy<T>>>,
+    mut query: Query<&mut RetryPolicy<T>>,
+) {
+    match trigger.event().entity() {
+        Some(entity) => if let Ok(mut policy) = query.get_mut(entity) { policy.reset() },
+        None => if let Some(mut policy) = resource { policy.reset() },
+    }
+}
+
+fn resource_retry_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Option<ResMut<Progress<T>>>,
+    policy: Option<ResMut<RetryPolicy<T>>>,
+) {
+    let (mut resource, mut policy) = match (resource, policy) {
+        (Some(resource), Some(policy)) => (resource, policy),
+        _ => return,
+    };
+
+    if !policy.ready() { return }
+
+    resource.clear_failures();
+    commands.trigger(RetryAttempt::<T>::new(policy.attempts, None));
+}
+
+fn entity_retry_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Progress<T>, &mut RetryPolicy<T>)>,
+) {
+    for (entity, mut tracker, mut policy) in &mut query {
+        if !policy.ready() { continue }
+
+        tracker.clear_failures();
+        commands.trigger_targets(RetryAttempt::<T>::new(policy.attempts, Some(entity)), entity);
+    }
+}
+
+/// How a [`Progress<T>`] tracker is retried when it fails, used by [`RetryPlugin<T>`].
+///
+/// Can be inserted as a [`Resource`] to retry the [`Progress<T>`] resource,
+/// or as a [`Component`] to retry the [`Progress<T>`] component on the same entity.
+/// Attempts are counted until the tracker completes.
+#[derive(Component, Resource)]
+pub struct RetryPolicy<T: ?Sized> {
+    max_attempts: u32,
+    backoff: Duration,
+    multiplier: f32,
+    max_backoff: Duration,
+    attempts: u32,
+    /// When the retry was scheduled, and how long it waits.
+    pending: Option<(Instant, Duration)>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> RetryPolicy<T> {
+    /// Creates a new [`RetryPolicy`], retrying up to `max_attempts` times without waiting.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            backoff: Duration::ZERO,
+            multiplier: 1.0,
+            max_backoff: Duration::from_secs(3600),
+            attempts: 0,
+            pending: None,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Waits for `backoff` after a failure before retrying.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Multiplies the backoff by `multiplier` after each attempt, such as `2.0` for exponential backoff.
+    ///
+    /// Multipliers that are negative or not finite are ignored, leaving the backoff constant.
+    pub fn with_backoff_multiplier(mut self, multiplier: f32) -> Self {
+        self.multiplier = match multiplier.is_finite() && multiplier >= 0.0 {
+            true => multiplier,
+            false => 1.0,
+        };
+
+        self
+    }
+
+    /// Limits how long the backoff can grow to with [`with_backoff_multiplier`](Self::with_backoff_multiplier).
+    /// Defaults to one hour.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Returns the number of times the tracker has been retried since it last completed.
+    #[inline]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns the maximum number of times the tracker is retried.
+    #[inline]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Resets the number of attempts, and cancels any retry waiting to start.
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+        self.pending = None;
+    }
+
+    /// Returns how long to wait before the next attempt, clamped to the maximum backoff.
+    fn next_backoff(&self) -> Duration {
+        let seconds = self.backoff.as_secs_f64() * (self.multiplier as f64).powi(self.attempts.min(i32::MAX as u32) as i32);
+
+        // Overflowing the duration means the backoff is far past the maximum anyway
+        Duration::try_from_secs_f64(seconds)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Returns `true` and clears the pending retry if it's due to start.
+    fn ready(&mut self) -> bool {
+        match self.pending {
+            Some((at, backoff)) if at.elapsed() >= backoff => {
+                self.pending = None;
+                true
+            },
+
+            _ => false,
+        }
+    }
+}
+
+/// An observer event raised when a failed tracker is retried by [`RetryPlugin<T>`].
+///
+/// The failed work has been removed from the tracker, and should be attempted again.
+#[derive(Event)]
+pub struct RetryAttempt<T: ?Sized> {
+    attempt: u32,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> RetryAttempt<T> {
+    fn new(attempt: u32, entity: Option<Entity>) -> Self {
+        Self {
+            attempt,
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns which attempt this is, starting at `1` for the first retry.
+    #[inline]
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the entity whose tracker is being retried, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
+
+/// An observer event raised when a tracker fails after all attempts allowed by its [`RetryPolicy<T>`].
+#[derive(Event)]
+pub struct RetryExhausted<T: ?Sized> {
+    attempts: u32,
+    failed: u64,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> RetryExhausted<T> {
+    /// Returns the number of times the tracker was retried.
+    #[inline]
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Returns the units of work that failed on the last attempt.
+    #[inline]
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+
+    /// Returns the entity whose tracker failed, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
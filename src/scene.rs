@@ -0,0 +1,129 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_scene::{InstanceId, SceneInstance, SceneSpawner};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Records the readiness of spawned scenes into the [`Progress<T>`] resource.
+///
+/// Scenes are tracked either by adding [`TrackSceneReady<T>`] to scene root entities,
+/// such as those spawned with a `SceneBundle` or `DynamicSceneBundle`,
+/// or by adding instances spawned with [`SceneSpawner`] directly to the [`SceneTracker<T>`] resource.
+pub struct SceneProgressPlugin<T: ?Sized> {
+    /// The schedule in which scene readiness is recorded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for SceneProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for SceneProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneTracker<T>>();
+
+        app.add_systems(self.schedule, scene_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn scene_progress_system<T: ?Sized + Send + Sync + 'static>(
+    spawner: Res<SceneSpawner>,
+    tracker: Res<SceneTracker<T>>,
+    roots: Query<Option<&SceneInstance>, With<TrackSceneReady<T>>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        for instance in &roots {
+            // The instance is only added once the scene asset has loaded
+            let ready = instance.is_some_and(|v| spawner.instance_is_ready(**v));
+            progress.track(ready as u32, 1);
+        }
+
+        for &instance in &tracker.instances {
+            let ready = spawner.instance_is_ready(instance);
+            progress.track(ready as u32, 1);
+        }
+    });
+}
+
+/// Marks a scene root entity as contributing to the [`Progress<T>`] resource.
+///
+/// The entity is one unit of work, which is done once its scene instance is ready.
+#[derive(Component)]
+pub struct TrackSceneReady<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> TrackSceneReady<T> {
+    /// Creates a new [`TrackSceneReady`] component.
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for TrackSceneReady<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A set of scene instances whose readiness contributes to the [`Progress<T>`] resource.
+///
+/// Each instance is one unit of work, which is done once the instance is ready.
+///
+/// Added by [`SceneProgressPlugin<T>`].
+#[derive(Resource)]
+pub struct SceneTracker<T: ?Sized> {
+    instances: Vec<InstanceId>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> SceneTracker<T> {
+    /// Adds a scene instance to be tracked.
+    pub fn add(&mut self, instance: InstanceId) {
+        self.instances.push(instance);
+    }
+
+    /// Stops tracking all scene instances.
+    pub fn clear(&mut self) {
+        self.instances.clear();
+    }
+
+    /// Returns the number of tracked scene instances.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    /// Returns `true` if no scene instances are tracked.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+}
+
+impl<T: ?Sized> Default for SceneTracker<T> {
+    fn default() -> Self {
+        Self {
+            instances: Vec::new(),
+            _p1: PhantomData,
+        }
+    }
+}
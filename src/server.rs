@@ -0,0 +1,61 @@
+use std::{io::{Read, Write}, net::{SocketAddr, TcpListener, TcpStream}, sync::{Arc, Mutex}};
+use bevy_utils::{tracing::warn, Duration};
+
+/// A response served to every request, updated from the app.
+#[derive(Clone)]
+pub(crate) struct Response {
+    pub status: &'static str,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+/// A response shared with the thread serving requests.
+pub(crate) type SharedResponse = Arc<Mutex<Response>>;
+
+/// Listens on `address`, answering every request with the latest response on a background thread.
+///
+/// Returns `None` if the address can't be listened on.
+pub(crate) fn spawn(name: &str, address: SocketAddr, response: Response) -> Option<SharedResponse> {
+    let listener = match TcpListener::bind(address) {
+        Ok(v) => v,
+        Err(err) => {
+            warn!("{name} couldn't listen on {address}: {err}");
+            return None;
+        },
+    };
+
+    let shared = Arc::new(Mutex::new(response));
+    let response = shared.clone();
+
+    std::thread::Builder::new()
+        .name(name.into())
+        .spawn(move || serve(listener, response))
+        .expect("failed to spawn server thread");
+
+    Some(shared)
+}
+
+fn serve(listener: TcpListener, response: SharedResponse) {
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let response = response.lock().unwrap().clone();
+
+        // Failing to answer one client shouldn't stop the server
+        let _ = respond(stream, &response);
+    }
+}
+
+fn respond(mut stream: TcpStream, response: &Response) -> std::io::Result<()> {
+    // The request is read but ignored, since every path serves the same response
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    let _ = stream.read(&mut [0; 1024])?;
+
+    write!(stream, "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status, response.content_type, response.body.len(), response.body)?;
+
+    stream.flush()
+}
@@ -0,0 +1,124 @@
+use std::{marker::PhantomData, sync::{atomic::{AtomicU64, Ordering}, Arc, Mutex, PoisonError}};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Records work from the [`SharedProgress<T>`] resource into the [`Progress<T>`] resource.
+pub struct SharedProgressPlugin<T: ?Sized> {
+    /// The schedule in which shared work is recorded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for SharedProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for SharedProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SharedProgress<T>>();
+
+        app.add_systems(self.schedule, shared_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn shared_progress_system<T: ?Sized + Send + Sync + 'static>(
+    shared: Res<SharedProgress<T>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    resource: Option<ResMut<Progress<T>>>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let mut synced = shared.inner.synced.lock().unwrap_or_else(PoisonError::into_inner);
+    let (done, total) = shared.work();
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        progress.record(done, total, 1.0);
+    });
+}
+
+/// A thread-safe handle for recording work from outside the ECS, such as in background tasks.
+///
+/// Clones of this handle share the same state, so it can be cloned into
+/// tasks spawned on a task pool, or into a plain [`std::thread`].
+/// Work recorded through the handle is cumulative, and is recorded into the
+/// [`Progress<T>`] resource every tick by [`SharedProgressPlugin<T>`].
+/// If the resource isn't reset every tick, only the work added since the last tick is recorded.
+#[derive(Resource)]
+pub struct SharedProgress<T: ?Sized> {
+    inner: Arc<SharedWork>,
+    _p1: PhantomData<T>,
+}
+
+#[derive(Default)]
+struct SharedWork {
+    done: AtomicU64,
+    total: AtomicU64,
+    /// The work as of when it was last recorded into the resource.
+    synced: Mutex<SyncedWork>,
+}
+
+impl<T: ?Sized> SharedProgress<T> {
+    /// Creates a new [`SharedProgress`] handle, with no work recorded.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(SharedWork::default()),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Records progress, including its total work and done work.
+    pub fn track(&self, done: u32, total: u32) {
+        self.add(done as u64, total as u64);
+    }
+
+    pub(crate) fn add(&self, done: u64, total: u64) {
+        // Total is always incremented before done, and read after it,
+        // so a reader never sees more done work than total work.
+        self.inner.total.fetch_add(total, Ordering::SeqCst);
+        self.inner.done.fetch_add(done, Ordering::SeqCst);
+    }
+
+    /// Returns the work that has been completed and the total units of work.
+    pub fn work(&self) -> (u64, u64) {
+        let done = self.inner.done.load(Ordering::SeqCst);
+        let total = self.inner.total.load(Ordering::SeqCst);
+        (done, total)
+    }
+
+    /// Clears all recorded work, for all clones of this handle.
+    pub fn clear(&self) {
+        let mut synced = self.inner.synced.lock().unwrap_or_else(PoisonError::into_inner);
+        self.inner.done.store(0, Ordering::SeqCst);
+        self.inner.total.store(0, Ordering::SeqCst);
+        *synced = SyncedWork::default();
+    }
+}
+
+impl<T: ?Sized> Default for SharedProgress<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Clone for SharedProgress<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _p1: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::Progress;
+
+impl<T: ?Sized> Progress<T> {
+    /// Records `skipped` units of work that were scheduled but intentionally not performed,
+    /// such as work with cached results.
+    ///
+    /// Skipped work counts as done work, but is reported separately by [`work_breakdown`](Self::work_breakdown).
+    pub fn skip(&mut self, skipped: u32) {
+        self.record(skipped as u64, skipped as u64, 1.0);
+        self.skipped = self.overflow.add(self.skipped, skipped as u64);
+    }
+
+    /// Returns the units of work that were skipped with [`skip`](Self::skip).
+    #[inline]
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
+    /// Returns the recorded work, broken down by outcome.
+    pub fn work_breakdown(&self) -> WorkBreakdown {
+        let (done, total) = self.work();
+
+        WorkBreakdown {
+            completed: done.saturating_sub(self.skipped),
+            skipped: self.skipped,
+            failed: self.failed(),
+            remaining: total.saturating_sub(done),
+        }
+    }
+}
+
+/// The work recorded by a [`Progress`] tracker, broken down by outcome.
+///
+/// Returned by [`Progress::work_breakdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkBreakdown {
+    completed: u64,
+    skipped: u64,
+    failed: u64,
+    remaining: u64,
+}
+
+impl WorkBreakdown {
+    /// Returns the units of work that were performed and completed.
+    #[inline]
+    pub fn completed(&self) -> u64 {
+        self.completed
+    }
+
+    /// Returns the units of work that were skipped.
+    /// These count as done work, but weren't performed.
+    #[inline]
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
+    /// Returns the units of work that have failed.
+    /// These are included in [`remaining`](Self::remaining), since failed work is never done.
+    #[inline]
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+
+    /// Returns the units of work that have not been completed or skipped.
+    #[inline]
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns the total units of work.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.completed + self.skipped + self.remaining
+    }
+}
@@ -0,0 +1,193 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_utils::Instant;
+use crate::{Progress, ProgressPaused, ProgressSystems};
+
+/// Updates [`SmoothedProgress<T>`] resources and components in [`ProgressSystems::Smooth`].
+pub struct SmoothedProgressPlugin<T: ?Sized> {
+    /// The schedule in which smoothed values are updated.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for SmoothedProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for SmoothedProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(self.schedule, ProgressSystems::Smooth
+            .after(ProgressSystems::Check));
+
+        app.add_systems(self.schedule, (
+            resource_smoothing_system::<T>,
+            entity_smoothing_system::<T>,
+        ).in_set(ProgressSystems::Smooth)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+fn resource_smoothing_system<T: ?Sized + Send + Sync + 'static>(
+    mut last: Local<Option<Instant>>,
+    resource: Option<Res<Progress<T>>>,
+    smoothed: Option<ResMut<SmoothedProgress<T>>>,
+) {
+    let delta = delta_seconds(&mut last);
+
+    if let (Some(resource), Some(mut smoothed)) = (resource, smoothed) {
+        smoothed.update(resource.fract(), delta);
+    }
+}
+
+fn entity_smoothing_system<T: ?Sized + Send + Sync + 'static>(
+    mut last: Local<Option<Instant>>,
+    mut query: Query<(&Progress<T>, &mut SmoothedProgress<T>)>,
+) {
+    let delta = delta_seconds(&mut last);
+
+    for (tracker, mut smoothed) in &mut query {
+        smoothed.update(tracker.fract(), delta);
+    }
+}
+
+fn delta_seconds(last: &mut Option<Instant>) -> f32 {
+    let now = Instant::now();
+    let delta = last.map_or(0.0, |v| now.duration_since(v).as_secs_f32());
+    *last = Some(now);
+    delta
+}
+
+/// A fraction that smoothly follows the fraction of a [`Progress<T>`] tracker, for display in UI.
+///
+/// Can be inserted as a [`Resource`] to follow the [`Progress<T>`] resource,
+/// or as a [`Component`] to follow the [`Progress<T>`] component on the same entity.
+/// Updated by [`SmoothedProgressPlugin<T>`].
+#[derive(Component, Resource)]
+pub struct SmoothedProgress<T: ?Sized> {
+    value: f32,
+    speed: f32,
+    easing: SmoothingEasing,
+    max_speed: Option<f32>,
+    monotonic: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> SmoothedProgress<T> {
+    /// Creates a new [`SmoothedProgress`], starting at `0.0`.
+    pub fn new() -> Self {
+        Self {
+            value: 0.0,
+            speed: 4.0,
+            easing: SmoothingEasing::Exponential,
+            max_speed: None,
+            monotonic: true,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Sets how quickly the value approaches the true fraction.
+    ///
+    /// How this is interpreted depends on the [`SmoothingEasing`], with larger values being faster.
+    /// Defaults to `4.0`.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets how the value approaches the true fraction.
+    /// Defaults to [`SmoothingEasing::Exponential`].
+    pub fn with_easing(mut self, easing: SmoothingEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Limits how much the value can change per second, such as `0.5` to take at least two seconds to fill.
+    ///
+    /// This stops the value jumping ahead when a lot of work is done at once.
+    pub fn with_max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = Some(max_speed);
+        self
+    }
+
+    /// Sets whether the value can decrease, such as when the total work grows.
+    ///
+    /// When `true` (the default), the value never goes backwards, until [`reset`](Self::reset) is called.
+    pub fn with_monotonic(mut self, monotonic: bool) -> Self {
+        self.monotonic = monotonic;
+        self
+    }
+
+    /// Returns the smoothed fraction, from `0.0` to `1.0`.
+    #[inline]
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Resets the value to `0.0`.
+    pub fn reset(&mut self) {
+        self.value = 0.0;
+    }
+
+    fn update(&mut self, target: f32, delta: f32) {
+        let target = target.clamp(0.0, 1.0);
+
+        let target = match self.monotonic {
+            true => target.max(self.value),
+            false => target,
+        };
+
+        let gap = target - self.value;
+
+        let step = match self.easing {
+            SmoothingEasing::Linear => gap.signum() * self.speed * delta,
+            SmoothingEasing::EaseOut => gap.signum() * self.speed * gap.abs().sqrt() * delta,
+            SmoothingEasing::Exponential => gap * (1.0 - (-self.speed * delta).exp()),
+        };
+
+        let step = match self.max_speed {
+            Some(max_speed) => step.clamp(-max_speed * delta, max_speed * delta),
+            None => step,
+        };
+
+        // Don't overshoot the target
+        self.value = match step.abs() >= gap.abs() {
+            true => target,
+            false => self.value + step,
+        };
+
+        // Exponential approach never quite arrives on its own
+        if (target - self.value).abs() < 1e-4 { self.value = target }
+    }
+}
+
+impl<T: ?Sized> Default for SmoothedProgress<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How [`SmoothedProgress`] approaches the true fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SmoothingEasing {
+    /// The value moves at a constant rate, with the speed being the change in fraction per second.
+    Linear,
+
+    /// The value slows down as it nears the target, arriving in a finite time.
+    ///
+    /// This follows a quadratic ease-out, with the speed scaling how quickly it arrives.
+    EaseOut,
+
+    /// The value covers a fixed proportion of the remaining distance each second.
+    /// The speed is the rate of exponential approach per second.
+    #[default]
+    Exponential,
+}
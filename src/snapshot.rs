@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use crate::{Progress, ResetPolicy, storage::TrackerComponent};
+
+#[cfg(feature="bevy_reflect")]
+use bevy_reflect::Reflect;
+
+#[cfg(feature="bevy_reflect")]
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+
+#[cfg(feature="serde")]
+use serde::{Serialize, Deserialize};
+
+/// The work recorded by a [`Progress<T>`] tracker at a point in time.
+///
+/// Snapshots are taken with [`Progress::snapshot`], and applied with [`Progress::restore`],
+/// so long-running work can be resumed after the app restarts.
+///
+/// The tracking plugins can also capture snapshots right before trackers are reset,
+/// inserting them as a resource or component alongside the tracker.
+/// This is enabled with the `snapshot_before_reset` option.
+#[derive(Component, Resource)]
+#[cfg_attr(feature="bevy_reflect", derive(Reflect), reflect(Component, Resource, type_path = false))]
+#[cfg_attr(feature="serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct ProgressSnapshot<T: ?Sized> {
+    done: u64,
+    total: u64,
+    weighted_done: f64,
+    weighted_total: f64,
+    float_done: f64,
+    float_total: f64,
+    hidden_done: u64,
+    hidden_total: u64,
+    skipped: u64,
+    expected_total: u64,
+    #[cfg_attr(feature="bevy_reflect", reflect(ignore))]
+    #[cfg_attr(feature="serde", serde(skip))]
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressSnapshot<T> {
+    /// Returns the work that had been completed and the total units of work when the snapshot was taken.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total.max(self.expected_total))
+    }
+}
+
+impl<T: ?Sized> Clone for ProgressSnapshot<T> {
+    fn clone(&self) -> Self {
+        Self {
+            done: self.done,
+            total: self.total,
+            weighted_done: self.weighted_done,
+            weighted_total: self.weighted_total,
+            float_done: self.float_done,
+            float_total: self.float_total,
+            hidden_done: self.hidden_done,
+            hidden_total: self.hidden_total,
+            skipped: self.skipped,
+            expected_total: self.expected_total,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Progress<T> {
+    /// Captures the work recorded by the tracker as a [`ProgressSnapshot`].
+    pub fn snapshot(&self) -> ProgressSnapshot<T> {
+        ProgressSnapshot {
+            done: self.done,
+            total: self.total,
+            weighted_done: self.weighted_done,
+            weighted_total: self.weighted_total,
+            float_done: self.float_done,
+            float_total: self.float_total,
+            hidden_done: self.hidden_done,
+            hidden_total: self.hidden_total,
+            skipped: self.skipped,
+            expected_total: self.expected_total,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Overwrites the work recorded by the tracker with a [`ProgressSnapshot`].
+    ///
+    /// With [`ResetPolicy::EveryTick`], restored work only lasts until the next reset,
+    /// so this is best used with [`ResetPolicy::OnDone`] or [`ResetPolicy::Manual`].
+    pub fn restore(&mut self, snapshot: ProgressSnapshot<T>) {
+        self.done = snapshot.done;
+        self.total = snapshot.total;
+        self.weighted_done = snapshot.weighted_done;
+        self.weighted_total = snapshot.weighted_total;
+        self.float_done = snapshot.float_done;
+        self.float_total = snapshot.float_total;
+        self.hidden_done = snapshot.hidden_done;
+        self.hidden_total = snapshot.hidden_total;
+        self.skipped = snapshot.skipped;
+        self.expected_total = snapshot.expected_total;
+    }
+}
+
+pub(crate) fn resource_snapshot_system<T: ?Sized + Send + Sync + 'static>(
+    policy: ResetPolicy,
+) -> impl FnMut(Commands, Option<Res<Progress<T>>>) {
+    move |mut commands, resource| {
+        if let Some(resource) = resource {
+            if !resource.resets_with(policy) { return }
+            commands.insert_resource(resource.snapshot());
+        }
+    }
+}
+
+pub(crate) fn entity_snapshot_system<T: ?Sized + Send + Sync + 'static, C: TrackerComponent<T>>(
+    policy: ResetPolicy,
+) -> impl FnMut(Commands, Query<(Entity, &C)>) {
+    move |mut commands, query| {
+        for (entity, tracker) in &query {
+            let tracker = tracker.tracker();
+            if !tracker.resets_with(policy) { continue }
+            commands.entity(entity).insert(tracker.snapshot());
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, entity::EntityHashMap};
+use bevy_utils::{tracing::{field::Empty, info_span, Span}, Instant};
+use crate::{Cancelled, Done, Progress, Started};
+
+/// Adds observers that open a span for each tracking session of `T`, if they haven't been added already.
+pub(crate) fn setup<T: ?Sized + Send + Sync + 'static>(app: &mut App) {
+    if app.world().contains_resource::<ProgressSpans<T>>() { return }
+    app.init_resource::<ProgressSpans<T>>();
+
+    app.observe(span_started_observer::<T>);
+    app.observe(span_done_observer::<T>);
+    app.observe(span_cancelled_observer::<T>);
+    app.observe(span_removed_observer::<T>);
+}
+
+/// Spans for trackers that have started, but not finished.
+#[derive(Resource)]
+struct ProgressSpans<T: ?Sized> {
+    resource: Option<(Span, Instant)>,
+    entities: EntityHashMap<(Span, Instant)>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressSpans<T> {
+    fn default() -> Self {
+        Self {
+            resource: None,
+            entities: EntityHashMap::default(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> ProgressSpans<T> {
+    fn take(&mut self, entity: Option<Entity>) -> Option<(Span, Instant)> {
+        match entity {
+            Some(entity) => self.entities.remove(&entity),
+            None => self.resource.take(),
+        }
+    }
+}
+
+fn close(span: Span, started: Instant, (done, total): (u64, u64), outcome: &'static str) {
+    span.record("done", done);
+    span.record("total", total);
+    span.record("elapsed_ms", started.elapsed().as_secs_f64() * 1000.0);
+    span.record("outcome", outcome);
+}
+
+fn span_started_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Started<T>>,
+    mut spans: ResMut<ProgressSpans<T>>,
+) {
+    let entity = trigger.event().entity();
+
+    let span = info_span!(
+        "progress",
+        tracker = std::any::type_name::<T>(),
+        entity = entity.map(|v| v.to_bits()),
+        done = Empty,
+        total = Empty,
+        elapsed_ms = Empty,
+        outcome = Empty,
+    );
+
+    // Replacing a span closes it, which only happens if a session was interrupted without an event
+    match entity {
+        Some(entity) => { spans.entities.insert(entity, (span, Instant::now())); },
+        None => { spans.resource = Some((span, Instant::now())); },
+    }
+}
+
+fn span_done_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Done<T>>,
+    mut spans: ResMut<ProgressSpans<T>>,
+) {
+    let event = trigger.event();
+    if let Some((span, started)) = spans.take(event.entity()) {
+        close(span, started, event.work(), "done");
+    }
+}
+
+fn span_cancelled_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<Cancelled<T>>,
+    mut spans: ResMut<ProgressSpans<T>>,
+) {
+    let event = trigger.event();
+    if let Some((span, started)) = spans.take(event.entity()) {
+        close(span, started, event.work(), "cancelled");
+    }
+}
+
+fn span_removed_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<OnRemove, Progress<T>>,
+    mut spans: ResMut<ProgressSpans<T>>,
+    query: Query<&Progress<T>>,
+) {
+    let entity = trigger.entity();
+    let work = query.get(entity).map_or((0, 0), |v| v.work());
+
+    if let Some((span, started)) = spans.take(Some(entity)) {
+        close(span, started, work, "removed");
+    }
+}
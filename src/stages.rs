@@ -0,0 +1,215 @@
+use std::{borrow::Cow, marker::PhantomData};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Records the overall progress of the [`ProgressStages<T>`] resource into the [`Progress<T>`] resource,
+/// advancing through stages as they complete.
+pub struct ProgressStagesPlugin<T: ?Sized> {
+    /// The schedule in which stages are advanced and recorded.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressStagesPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressStagesPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, stages_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn stages_progress_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    stages: Option<ResMut<ProgressStages<T>>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    let mut stages = match stages {
+        Some(v) => v,
+        None => return,
+    };
+
+    while let Some(stage) = stages.stages.get(stages.current) {
+        if !stage.done() { break }
+
+        commands.trigger(StageCompleted::<T>::new(stages.current, stage.name.clone()));
+        stages.current += 1;
+    }
+
+    if let Some(mut resource) = resource {
+        // Recorded as one unit, so it completes exactly when the last stage does
+        let fract = stages.fract() as f64;
+        record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+            progress.track_f64(fract, 1.0);
+        });
+    }
+}
+
+/// Progress split into ordered, named stages, each contributing a weighted share of the overall progress.
+///
+/// Work is recorded into the current stage, which advances once all its work is done,
+/// raising [`StageCompleted<T>`]. Like [`ProgressTask<T>`](crate::ProgressTask), the state of
+/// stages is not reset every tick, and overall progress is recorded into the [`Progress<T>`]
+/// resource each tick by [`ProgressStagesPlugin<T>`].
+#[derive(Resource)]
+pub struct ProgressStages<T: ?Sized> {
+    stages: Vec<Stage>,
+    current: usize,
+    _p1: PhantomData<T>,
+}
+
+struct Stage {
+    name: Cow<'static, str>,
+    weight: f32,
+    done: u64,
+    total: u64,
+    finished: bool,
+}
+
+impl Stage {
+    fn done(&self) -> bool {
+        self.finished || (self.total > 0 && self.done >= self.total)
+    }
+
+    fn fract(&self) -> f32 {
+        if self.done() { return 1.0 }
+        if self.total == 0 { return 0.0 }
+        (self.done as f32 / self.total as f32).min(1.0)
+    }
+}
+
+impl<T: ?Sized> ProgressStages<T> {
+    /// Creates a new [`ProgressStages`] with no stages.
+    pub fn new() -> Self {
+        Self {
+            stages: Vec::new(),
+            current: 0,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Adds a stage after all existing stages, contributing `weight` to the overall progress.
+    pub fn with_stage(mut self, name: impl Into<Cow<'static, str>>, weight: f32) -> Self {
+        self.stages.push(Stage {
+            name: name.into(),
+            weight,
+            done: 0,
+            total: 0,
+            finished: false,
+        });
+
+        self
+    }
+
+    /// Adds to the done and total work of the current stage.
+    ///
+    /// Does nothing if all stages are complete.
+    pub fn track(&mut self, done: u32, total: u32) {
+        if let Some(stage) = self.stages.get_mut(self.current) {
+            stage.done += done as u64;
+            stage.total += total as u64;
+        }
+    }
+
+    /// Marks the current stage as complete, even if it has work left or no work at all.
+    pub fn finish_stage(&mut self) {
+        if let Some(stage) = self.stages.get_mut(self.current) {
+            stage.finished = true;
+        }
+    }
+
+    /// Returns the index and name of the current stage, or `None` if all stages are complete.
+    pub fn current(&self) -> Option<(usize, &str)> {
+        self.stages.get(self.current).map(|stage| (self.current, stage.name.as_ref()))
+    }
+
+    /// Returns the number of stages.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Returns `true` if there are no stages.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Returns the progress of the stage at `index` as a fraction, or `None` if it doesn't exist.
+    ///
+    /// Stages before the current stage are always `1.0`, and stages after it are always `0.0`.
+    pub fn stage_fract(&self, index: usize) -> Option<f32> {
+        let stage = self.stages.get(index)?;
+
+        Some(match index.cmp(&self.current) {
+            std::cmp::Ordering::Less => 1.0,
+            std::cmp::Ordering::Equal => stage.fract(),
+            std::cmp::Ordering::Greater => 0.0,
+        })
+    }
+
+    /// Returns the overall progress as a fraction, with each stage scaled by its weight.
+    pub fn fract(&self) -> f32 {
+        if self.current >= self.stages.len() { return 1.0 }
+
+        let total: f32 = self.stages.iter().map(|stage| stage.weight).sum();
+        if total <= 0.0 { return 0.0 }
+
+        let done: f32 = self.stages.iter().enumerate()
+            .map(|(index, stage)| stage.weight * self.stage_fract(index).unwrap_or(0.0))
+            .sum();
+
+        (done / total).min(1.0)
+    }
+}
+
+impl<T: ?Sized> Default for ProgressStages<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An observer event raised when a stage of the [`ProgressStages<T>`] resource completes.
+#[derive(Event)]
+pub struct StageCompleted<T: ?Sized> {
+    index: usize,
+    name: Cow<'static, str>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> StageCompleted<T> {
+    fn new(index: usize, name: Cow<'static, str>) -> Self {
+        Self {
+            index,
+            name,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the index of the completed stage.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of the completed stage.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use bevy_utils::{Duration, Instant};
+use crate::Progress;
+
+/// How long a tracker can go without forward progress before it's considered stalled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallThreshold {
+    /// The tracker is stalled after being checked this many times without progress.
+    Frames(u32),
+
+    /// The tracker is stalled after this much real time has passed without progress.
+    Duration(Duration),
+}
+
+impl<T: ?Sized> Progress<T> {
+    /// Enables stall detection, raising [`Stalled`] if the tracker makes no forward progress for `threshold`.
+    ///
+    /// A tracker makes forward progress when its completed work increases between checks.
+    /// Empty and completed trackers are never considered stalled.
+    pub fn with_stall_threshold(mut self, threshold: StallThreshold) -> Self {
+        self.stall.threshold = Some(threshold);
+        self
+    }
+
+    /// Returns `true` if the tracker is currently stalled.
+    ///
+    /// This is always `false` if stall detection is not enabled.
+    pub fn stalled(&self) -> bool {
+        self.stall.reported
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct StallState {
+    threshold: Option<StallThreshold>,
+    frames: u32,
+    since: Instant,
+    reported: bool,
+}
+
+impl StallState {
+    pub(crate) fn new() -> Self {
+        Self {
+            threshold: None,
+            frames: 0,
+            since: Instant::now(),
+            reported: false,
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        *self = Self {
+            threshold: self.threshold,
+            ..Self::new()
+        };
+    }
+
+    pub(crate) fn enabled(&self) -> bool {
+        self.threshold.is_some()
+    }
+
+    /// Returns how long the tracker has been stuck, if it has just become stalled.
+    pub(crate) fn check(&mut self, progressed: bool) -> Option<(u32, Duration)> {
+        let threshold = self.threshold?;
+
+        if progressed {
+            self.frames = 0;
+            self.since = Instant::now();
+            self.reported = false;
+            return None;
+        }
+
+        self.frames += 1;
+        let elapsed = self.since.elapsed();
+        if self.reported { return None }
+
+        let stalled = match threshold {
+            StallThreshold::Frames(frames) => self.frames >= frames,
+            StallThreshold::Duration(duration) => elapsed >= duration,
+        };
+
+        if !stalled { return None }
+        self.reported = true;
+        Some((self.frames, elapsed))
+    }
+}
+
+/// An observer event raised when a progress tracker stops making forward progress.
+///
+/// This is only raised for trackers with stall detection enabled,
+/// using [`Progress::with_stall_threshold`].
+#[derive(Event)]
+pub struct Stalled<T: ?Sized> {
+    frames: u32,
+    duration: Duration,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Stalled<T> {
+    pub(crate) fn new(frames: u32, duration: Duration, entity: Option<Entity>) -> Self {
+        Self {
+            frames,
+            duration,
+            entity,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the number of checks the tracker has gone without progress.
+    #[inline]
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// Returns how long the tracker has gone without progress.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the entity whose tracker stalled, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
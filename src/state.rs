@@ -0,0 +1,100 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_state::{prelude::*, state::FreelyMutableState};
+use crate::{Done, EntityProgressTrackingPlugin, Progress, ResourceProgressTrackingPlugin};
+
+/// Transitions to a state when the [`Progress<T>`](crate::Progress) resource completes.
+///
+/// When [`Done<T>`] is raised for the resource, [`NextState<S>`] is set to `target`.
+/// Completion of entity trackers is ignored, as is [`Done<T>`] raised for an empty resource,
+/// so the state doesn't change before any work has been recorded.
+pub struct TransitionOnDone<T: ?Sized, S: FreelyMutableState> {
+    /// The state to transition to.
+    pub target: S,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized, S: FreelyMutableState> TransitionOnDone<T, S> {
+    /// Creates a new [`TransitionOnDone`] plugin, transitioning to `target`.
+    pub fn new(target: S) -> Self {
+        Self {
+            target,
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, S: FreelyMutableState> Plugin for TransitionOnDone<T, S> {
+    fn build(&self, app: &mut App) {
+        let target = self.target.clone();
+
+        app.observe(move |trigger: Trigger<Done<T>>, mut next: ResMut<NextState<S>>| {
+            let event = trigger.event();
+            if event.entity().is_some() || !event.has_completed() { return }
+            next.set(target.clone());
+        });
+    }
+}
+
+/// Adds progress tracking for `T`, scoped to a state.
+///
+/// The [`Progress<T>`] resource is initialised when entering `state`, and removed when exiting it.
+/// [`Progress<T>`] components are also removed from all entities when exiting `state`.
+/// While not in `state`, trackers for `T` are neither checked nor reset.
+///
+/// This replaces [`ProgressTrackingPlugin<T>`](crate::ProgressTrackingPlugin), and should not be used alongside it.
+pub struct StateScopedProgressPlugin<T: ?Sized, S: States> {
+    /// The state that trackers are scoped to.
+    pub state: S,
+
+    /// The schedule in which the progress value is checked.
+    pub check_schedule: InternedScheduleLabel,
+
+    /// The schedule in which the progress value is reset.
+    /// This should be the same as, or after, `check_schedule`.
+    pub reset_schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized, S: States> StateScopedProgressPlugin<T, S> {
+    /// Creates a new [`StateScopedProgressPlugin`], scoped to `state`.
+    pub fn new(state: S) -> Self {
+        Self {
+            state,
+            check_schedule: PostUpdate.intern(),
+            reset_schedule: Last.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, S: States> Plugin for StateScopedProgressPlugin<T, S> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(self.state.clone()), |mut commands: Commands| {
+            commands.init_resource::<Progress<T>>();
+        });
+
+        app.add_systems(OnExit(self.state.clone()), |mut commands: Commands, query: Query<Entity, With<Progress<T>>>| {
+            commands.remove_resource::<Progress<T>>();
+
+            for entity in &query {
+                commands.entity(entity).remove::<Progress<T>>();
+            }
+        });
+
+        ResourceProgressTrackingPlugin::<T> {
+            check_schedule: self.check_schedule,
+            reset_schedule: self.reset_schedule,
+            ..Default::default()
+        }.build_with(app, in_state(self.state.clone()));
+
+        EntityProgressTrackingPlugin::<T> {
+            check_schedule: self.check_schedule,
+            reset_schedule: self.reset_schedule,
+            ..Default::default()
+        }.build_with(app, in_state(self.state.clone()));
+    }
+}
@@ -0,0 +1,102 @@
+use std::ops::{Deref, DerefMut};
+use bevy_ecs::prelude::*;
+use crate::Progress;
+
+/// A [`Progress<T>`] tracker stored in a sparse set, rather than in tables.
+///
+/// Sparse set storage makes inserting and removing the tracker cheap,
+/// which suits short-lived work on long-lived entities, such as a unit building something.
+/// Iterating over many trackers is slower than with table storage.
+///
+/// This is used instead of a [`Progress<T>`] component when the
+/// [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin)
+/// is configured with [`ProgressStorage::SparseSet`], and dereferences to the inner tracker.
+/// Only the core tracking systems and the [`ProgressRegistry`](crate::ProgressRegistry) support it:
+/// features that query [`Progress<T>`] components, such as smoothing and hierarchies,
+/// won't see trackers stored this way.
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+pub struct SparseProgress<T: ?Sized>(Progress<T>);
+
+impl<T: ?Sized> SparseProgress<T> {
+    /// Creates a new [`SparseProgress`] from a tracker.
+    pub fn new(progress: Progress<T>) -> Self {
+        Self(progress)
+    }
+
+    /// Returns the inner tracker.
+    #[inline]
+    pub fn into_inner(self) -> Progress<T> {
+        self.0
+    }
+}
+
+impl<T: ?Sized> Default for SparseProgress<T> {
+    fn default() -> Self {
+        Self(Progress::default())
+    }
+}
+
+impl<T: ?Sized> Deref for SparseProgress<T> {
+    type Target = Progress<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: ?Sized> DerefMut for SparseProgress<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: ?Sized> From<Progress<T>> for SparseProgress<T> {
+    #[inline]
+    fn from(value: Progress<T>) -> Self {
+        Self(value)
+    }
+}
+
+/// How [`Progress<T>`] trackers on entities are stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressStorage {
+    /// Trackers are [`Progress<T>`] components, stored in tables.
+    #[default]
+    Table,
+
+    /// Trackers are [`SparseProgress<T>`] components, stored in sparse sets.
+    SparseSet,
+}
+
+/// A component holding a tracker, letting the entity systems work with any [`ProgressStorage`].
+pub(crate) trait TrackerComponent<T: ?Sized>: Component {
+    fn tracker(&self) -> &Progress<T>;
+    fn tracker_mut(&mut self) -> &mut Progress<T>;
+}
+
+impl<T: ?Sized + Send + Sync + 'static> TrackerComponent<T> for Progress<T> {
+    #[inline]
+    fn tracker(&self) -> &Progress<T> {
+        self
+    }
+
+    #[inline]
+    fn tracker_mut(&mut self) -> &mut Progress<T> {
+        self
+    }
+}
+
+impl<T: ?Sized + Send + Sync + 'static> TrackerComponent<T> for SparseProgress<T> {
+    #[inline]
+    fn tracker(&self) -> &Progress<T> {
+        &self.0
+    }
+
+    #[inline]
+    fn tracker_mut(&mut self) -> &mut Progress<T> {
+        &mut self.0
+    }
+}
@@ -0,0 +1,110 @@
+use std::{borrow::Cow, marker::PhantomData};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, EmptyFract, Progress, ProgressPaused, ProgressSystems};
+
+/// Aggregates all [`ProgressTask<T>`] components into the [`Progress<T>`] resource.
+pub struct ProgressTaskPlugin<T: ?Sized> {
+    /// The schedule in which tasks are aggregated.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressTaskPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressTaskPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, task_progress_system::<T>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn task_progress_system<T: ?Sized + Send + Sync + 'static>(
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+    tasks: Query<&ProgressTask<T>>,
+) {
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        for task in &tasks {
+            progress.record(task.done, task.total, 1.0);
+        }
+    });
+}
+
+/// A named unit of work, contributing to the [`Progress<T>`] resource.
+///
+/// Unlike [`Progress<T>`], the state of a task is not reset every tick.
+/// Instead, its work is added to the resource tracker each tick by [`ProgressTaskPlugin<T>`].
+#[derive(Component)]
+pub struct ProgressTask<T: ?Sized> {
+    label: Cow<'static, str>,
+    done: u64,
+    total: u64,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressTask<T> {
+    /// Creates a new [`ProgressTask`] with a human-readable label.
+    pub fn new(label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            label: label.into(),
+            done: 0,
+            total: 0,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the label of the task.
+    #[inline]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Adds to the done and total work of the task.
+    pub fn track(&mut self, done: u32, total: u32) {
+        self.done += done as u64;
+        self.total += total as u64;
+    }
+
+    /// Overwrites the done and total work of the task.
+    pub fn set(&mut self, done: u64, total: u64) {
+        self.done = done;
+        self.total = total;
+    }
+
+    /// Returns the work that has been completed and the total units of work.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns `true` if all of the task's work has been completed.
+    pub fn is_done(&self) -> bool {
+        self.done >= self.total
+    }
+
+    /// Returns the progress of the task as a fraction, from `0.0` (no work done) to `1.0` (all work done).
+    ///
+    /// Like [`Progress::fract`], tasks without work return the default [`EmptyFract`] value.
+    pub fn fract(&self) -> f32 {
+        if self.total == 0 { return EmptyFract::default().value() }
+        (self.done as f64 / self.total as f64).min(1.0) as f32
+    }
+}
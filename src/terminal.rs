@@ -0,0 +1,111 @@
+use std::{borrow::Cow, marker::PhantomData};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use indicatif::{ProgressBar, ProgressStyle};
+use crate::{Progress, ProgressPaused, ProgressSystems};
+
+/// Drives a terminal progress bar from the [`Progress<T>`] resource, using [`indicatif`].
+///
+/// The bar is stored in the [`TerminalProgressBar<T>`] resource, which can be used to customise it further.
+pub struct TerminalProgressPlugin<T: ?Sized> {
+    /// The schedule in which the bar is updated.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    /// The template used to style the bar. See [`ProgressStyle::with_template`].
+    pub template: Cow<'static, str>,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for TerminalProgressPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            template: Cow::Borrowed("{msg} [{bar:40}] {pos}/{len} ({eta})"),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> TerminalProgressPlugin<T> {
+    /// Sets the template used to style the bar.
+    pub fn with_template(mut self, template: impl Into<Cow<'static, str>>) -> Self {
+        self.template = template.into();
+        self
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for TerminalProgressPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let style = ProgressStyle::with_template(&self.template)
+            .expect("invalid progress bar template")
+            .progress_chars("#>.");
+
+        let bar = ProgressBar::hidden().with_style(style);
+        bar.set_message(bevy_utils::get_short_name(std::any::type_name::<T>()));
+        app.insert_resource(TerminalProgressBar::<T>::new(bar));
+
+        app.add_systems(self.schedule, terminal_progress_system::<T>
+            .after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+fn terminal_progress_system<T: ?Sized + Send + Sync + 'static>(
+    mut terminal: ResMut<TerminalProgressBar<T>>,
+    resource: Option<Res<Progress<T>>>,
+) {
+    let resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    let (done, total) = resource.work();
+
+    // Nothing to draw for an empty tracker
+    if total == 0 { return }
+
+    // More work after finishing means a new set of work
+    if terminal.finished {
+        if resource.is_done() { return }
+        terminal.finished = false;
+        terminal.bar.reset();
+    }
+
+    if terminal.bar.is_hidden() {
+        terminal.bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+    }
+
+    terminal.bar.set_length(total);
+    terminal.bar.set_position(done);
+
+    if resource.is_done() {
+        terminal.finished = true;
+        terminal.bar.finish();
+    }
+}
+
+/// The terminal progress bar for the [`Progress<T>`] resource, maintained by [`TerminalProgressPlugin<T>`].
+#[derive(Resource)]
+pub struct TerminalProgressBar<T: ?Sized> {
+    bar: ProgressBar,
+    finished: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> TerminalProgressBar<T> {
+    fn new(bar: ProgressBar) -> Self {
+        Self {
+            bar,
+            finished: false,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the underlying [`ProgressBar`].
+    #[inline]
+    pub fn bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+}
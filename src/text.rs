@@ -0,0 +1,113 @@
+use std::{borrow::Cow, marker::PhantomData};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_text::Text;
+use crate::{Progress, ProgressSystems};
+
+/// Keeps [`ProgressText<T>`] components synchronised with [`Progress<T>`] trackers.
+pub struct ProgressTextPlugin<T: ?Sized> {
+    /// The schedule in which text is updated.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressTextPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressTextPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, progress_text_system::<T>
+            .after(ProgressSystems::Check));
+    }
+}
+
+fn progress_text_system<T: ?Sized + Send + Sync + 'static>(
+    resource: Option<Res<Progress<T>>>,
+    trackers: Query<&Progress<T>>,
+    mut texts: Query<(&ProgressText<T>, &mut Text)>,
+) {
+    for (binding, mut text) in &mut texts {
+        let progress = match binding.source {
+            Some(entity) => trackers.get(entity).ok(),
+            None => resource.as_deref(),
+        };
+
+        let progress = match progress {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let section = match text.bypass_change_detection().sections.first_mut() {
+            Some(v) => v,
+            None => continue,
+        };
+
+        // Avoid triggering change detection every tick
+        let value = binding.format(progress);
+        if section.value == value { continue }
+        section.value = value;
+        text.set_changed();
+    }
+}
+
+/// Displays the state of a [`Progress<T>`] tracker in the first section of a [`Text`] component.
+///
+/// The format string can contain the following placeholders:
+/// - `{done}`, replaced with the done units of work.
+/// - `{total}`, replaced with the total units of work.
+/// - `{percent}`, replaced with the fraction as a whole percentage, rounded down.
+///
+/// Updated every tick by [`ProgressTextPlugin<T>`].
+#[derive(Component)]
+pub struct ProgressText<T: ?Sized> {
+    /// The format string.
+    pub format: Cow<'static, str>,
+
+    /// The entity whose [`Progress<T>`] is shown, or `None` to show the resource.
+    pub source: Option<Entity>,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressText<T> {
+    /// Creates a new [`ProgressText`] showing the [`Progress<T>`] resource.
+    pub fn new(format: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            format: format.into(),
+            source: None,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Shows the [`Progress<T>`] component of `entity`, instead of the resource.
+    pub fn with_source(mut self, entity: Entity) -> Self {
+        self.source = Some(entity);
+        self
+    }
+
+    fn format(&self, progress: &Progress<T>) -> String {
+        let (done, total) = progress.work();
+
+        // Rounded down, so 100% is only shown once all work is done
+        let percent = (progress.fract_f64() * 100.0).floor();
+
+        self.format
+            .replace("{done}", &done.to_string())
+            .replace("{total}", &total.to_string())
+            .replace("{percent}", &format!("{percent:.0}"))
+    }
+}
+
+impl<T: ?Sized> Default for ProgressText<T> {
+    fn default() -> Self {
+        Self::new("{done}/{total} ({percent}%)")
+    }
+}
@@ -0,0 +1,119 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_time::{Time, Timer, TimerMode};
+use bevy_utils::Duration;
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems, ResetPolicy};
+
+/// Ticks [`TimedWork<T>`] resources and components, recording them into [`Progress<T>`].
+///
+/// Requires [`Time`] to be available, usually through `TimePlugin`.
+pub struct TimedWorkPlugin<T: ?Sized> {
+    /// The schedule in which timers are ticked.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for TimedWorkPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for TimedWorkPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, (timed_resource_system::<T>, timed_entity_system::<T>)
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn timed_resource_system<T: ?Sized + Send + Sync + 'static>(
+    time: Res<Time>,
+    timed: Option<ResMut<TimedWork<T>>>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+) {
+    let mut timed = match timed {
+        Some(v) => v,
+        None => return,
+    };
+
+    timed.timer.tick(time.delta());
+
+    if let Some(mut resource) = resource {
+        timed.record(policies.and_then(|v| v.resource), &mut resource);
+    }
+}
+
+fn timed_entity_system<T: ?Sized + Send + Sync + 'static>(
+    time: Res<Time>,
+    mut query: Query<(&mut TimedWork<T>, Option<&mut Progress<T>>)>,
+    policies: Option<Res<ResetPolicies<T>>>,
+) {
+    let policy = policies.and_then(|v| v.entities);
+
+    for (mut timed, tracker) in &mut query {
+        timed.timer.tick(time.delta());
+
+        if let Some(mut tracker) = tracker {
+            timed.record(policy, &mut tracker);
+        }
+    }
+}
+
+/// A unit of work that completes after a fixed amount of time, such as showing a logo for three seconds.
+///
+/// This lets minimum durations take part in the same tracker as real work.
+/// As a resource, it contributes to the [`Progress<T>`] resource.
+/// As a component, it contributes to the [`Progress<T>`] component of the same entity.
+///
+/// The elapsed fraction of the timer is recorded each tick as fractional work,
+/// as in [`Progress::track_f64`]. Ticked by [`TimedWorkPlugin<T>`].
+#[derive(Component, Resource)]
+pub struct TimedWork<T: ?Sized> {
+    timer: Timer,
+    synced: SyncedWork,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> TimedWork<T> {
+    /// Creates a new [`TimedWork`] that completes after `duration`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            timer: Timer::new(duration, TimerMode::Once),
+            synced: SyncedWork::default(),
+            _p1: PhantomData,
+        }
+    }
+
+    /// Creates a new [`TimedWork`] that completes after `seconds`.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+
+    /// Returns the underlying [`Timer`].
+    #[inline]
+    pub fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    /// Returns `true` if the timer has finished.
+    #[inline]
+    pub fn finished(&self) -> bool {
+        self.timer.finished()
+    }
+
+    fn record(&mut self, policy: Option<ResetPolicy>, progress: &mut Progress<T>) {
+        let fraction = self.timer.fraction() as f64;
+        record_full_state(policy, progress, &mut self.synced, |progress| {
+            progress.track_f64(fraction, 1.0);
+        });
+    }
+}
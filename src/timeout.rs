@@ -0,0 +1,172 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_utils::Duration;
+use crate::{CancelProgress, Phase, Progress, ProgressPaused, ProgressSystems};
+
+/// Raises [`TimedOut<T>`] for trackers that don't complete within their [`ProgressTimeout<T>`].
+pub struct ProgressTimeoutPlugin<T: ?Sized> {
+    /// The schedule in which timeouts are checked.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressTimeoutPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressTimeoutPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, (
+            resource_timeout_system::<T>,
+            entity_timeout_system::<T>,
+        ).after(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>)));
+    }
+}
+
+fn resource_timeout_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    resource: Option<Res<Progress<T>>>,
+    timeout: Option<ResMut<ProgressTimeout<T>>>,
+) {
+    let (resource, mut timeout) = match (resource, timeout) {
+        (Some(resource), Some(timeout)) => (resource, timeout),
+        _ => return,
+    };
+
+    let event = match timeout.check(&resource, None) {
+        Some(v) => v,
+        None => return,
+    };
+
+    commands.trigger(event);
+
+    if timeout.cancel {
+        commands.trigger(CancelProgress::<T>::new());
+    }
+}
+
+fn entity_timeout_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Progress<T>, &mut ProgressTimeout<T>)>,
+) {
+    for (entity, tracker, mut timeout) in &mut query {
+        let event = match timeout.check(tracker, Some(entity)) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        commands.trigger_targets(event, entity);
+
+        if timeout.cancel {
+            commands.trigger_targets(CancelProgress::<T>::new(), entity);
+        }
+    }
+}
+
+/// A time limit for a [`Progress<T>`] tracker to complete in, checked by [`ProgressTimeoutPlugin<T>`].
+///
+/// Can be inserted as a [`Resource`] to limit the [`Progress<T>`] resource,
+/// or as a [`Component`] to limit the [`Progress<T>`] component on the same entity.
+/// The time is measured from when the tracker started, as returned by [`Progress::elapsed`].
+#[derive(Component, Resource)]
+pub struct ProgressTimeout<T: ?Sized> {
+    duration: Duration,
+    cancel: bool,
+    fired: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> ProgressTimeout<T> {
+    /// Creates a new [`ProgressTimeout`], raising [`TimedOut<T>`] if the tracker doesn't complete within `duration`.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            cancel: false,
+            fired: false,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Also cancels the tracker with [`CancelProgress<T>`] when it times out.
+    pub fn cancelling(mut self) -> Self {
+        self.cancel = true;
+        self
+    }
+
+    /// Returns the time the tracker has to complete in.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns `true` if the tracker has timed out in its current run.
+    #[inline]
+    pub fn timed_out(&self) -> bool {
+        self.fired
+    }
+
+    fn check(&mut self, progress: &Progress<T>, entity: Option<Entity>) -> Option<TimedOut<T>> {
+        // Only running trackers can time out, and only once per run
+        if progress.phase != Phase::Running {
+            self.fired = false;
+            return None;
+        }
+
+        if self.fired { return None }
+
+        let elapsed = progress.elapsed()?;
+        if elapsed < self.duration { return None }
+
+        self.fired = true;
+        let (done, total) = progress.work();
+
+        Some(TimedOut {
+            done,
+            total,
+            elapsed,
+            entity,
+            _p1: PhantomData,
+        })
+    }
+}
+
+/// An observer event raised when a tracker doesn't complete within its [`ProgressTimeout<T>`].
+///
+/// This is raised once per run of the tracker.
+#[derive(Event)]
+pub struct TimedOut<T: ?Sized> {
+    done: u64,
+    total: u64,
+    elapsed: Duration,
+    entity: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> TimedOut<T> {
+    /// Returns the work that had been completed and the total units of work when the tracker timed out.
+    #[inline]
+    pub fn work(&self) -> (u64, u64) {
+        (self.done, self.total)
+    }
+
+    /// Returns how long the tracker had been running.
+    #[inline]
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the entity whose tracker timed out, or `None` if the tracker was a resource.
+    #[inline]
+    pub fn entity(&self) -> Option<Entity> {
+        self.entity
+    }
+}
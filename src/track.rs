@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+use bevy_ecs::prelude::*;
+use crate::{Progress, storage::TrackerComponent};
+
+/// An event that can be triggered to record work into a progress tracker.
+///
+/// Trigger this without a target to record work into the [`Progress<T>`] resource,
+/// or targeted at entities to record work into their [`Progress<T>`] components.
+/// The work is held until right before [`ProgressSystems::Check`](crate::ProgressSystems::Check),
+/// so it's never lost to a reset, no matter when the event is triggered.
+/// This lets observers, hooks, and other plugins record progress without access to the trackers.
+///
+/// This is handled by the tracking plugins, requiring
+/// [`ResourceProgressTrackingPlugin<T>`](crate::ResourceProgressTrackingPlugin) or
+/// [`EntityProgressTrackingPlugin<T>`](crate::EntityProgressTrackingPlugin) respectively.
+#[derive(Event)]
+pub struct TrackWork<T: ?Sized> {
+    done: u32,
+    total: u32,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> TrackWork<T> {
+    /// Creates a new [`TrackWork`] event, recording work as in [`Progress::track`].
+    pub fn new(done: u32, total: u32) -> Self {
+        Self {
+            done,
+            total,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns the work to be recorded, as completed and total units of work.
+    #[inline]
+    pub fn work(&self) -> (u32, u32) {
+        (self.done, self.total)
+    }
+}
+
+/// Work recorded with [`TrackWork`], waiting to be applied.
+#[derive(Resource)]
+pub(crate) struct PendingWork<T: ?Sized> {
+    resource: Vec<(u32, u32)>,
+    entities: Vec<(Entity, u32, u32)>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for PendingWork<T> {
+    fn default() -> Self {
+        Self {
+            resource: Vec::new(),
+            entities: Vec::new(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn resource_track_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<TrackWork<T>>,
+    mut pending: ResMut<PendingWork<T>>,
+) {
+    // Targeted events are for entity trackers
+    if trigger.entity() != Entity::PLACEHOLDER { return }
+
+    let event = trigger.event();
+    pending.resource.push((event.done, event.total));
+}
+
+pub(crate) fn entity_track_observer<T: ?Sized + Send + Sync + 'static>(
+    trigger: Trigger<TrackWork<T>>,
+    mut pending: ResMut<PendingWork<T>>,
+) {
+    let entity = trigger.entity();
+    if entity == Entity::PLACEHOLDER { return }
+
+    let event = trigger.event();
+    pending.entities.push((entity, event.done, event.total));
+}
+
+pub(crate) fn resource_pending_system<T: ?Sized + Send + Sync + 'static>(
+    mut pending: ResMut<PendingWork<T>>,
+    resource: Option<ResMut<Progress<T>>>,
+) {
+    if pending.resource.is_empty() { return }
+    let work = std::mem::take(&mut pending.resource);
+
+    if let Some(mut resource) = resource {
+        for (done, total) in work {
+            resource.track(done, total);
+        }
+    }
+}
+
+pub(crate) fn entity_pending_system<T: ?Sized + Send + Sync + 'static, C: TrackerComponent<T>>(
+    mut pending: ResMut<PendingWork<T>>,
+    mut query: Query<&mut C>,
+) {
+    if pending.entities.is_empty() { return }
+
+    for (entity, done, total) in pending.entities.drain(..) {
+        if let Ok(mut tracker) = query.get_mut(entity) {
+            tracker.tracker_mut().track(done, total);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_tasks::{block_on, poll_once, Task};
+use crate::{reset::{record_full_state, ResetPolicies, SyncedWork}, tracking_enabled, Progress, ProgressPaused, ProgressSystems};
+
+/// Polls [`TrackedTask<T, R>`] components, recording them into the [`Progress<T>`] resource.
+pub struct TrackedTaskPlugin<T: ?Sized, R> {
+    /// The schedule in which tasks are polled.
+    /// This records work, so it must run before the check. See [`ProgressSystems`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+    _p2: PhantomData<R>,
+}
+
+impl<T: ?Sized, R> Default for TrackedTaskPlugin<T, R> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+            _p2: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static, R: Send + Sync + 'static> Plugin for TrackedTaskPlugin<T, R> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, tracked_task_system::<T, R>
+            .before(ProgressSystems::Check)
+            .run_if(not(resource_exists::<ProgressPaused<T>>))
+            .run_if(tracking_enabled::<T>));
+    }
+}
+
+fn tracked_task_system<T: ?Sized + Send + Sync + 'static, R: Send + Sync + 'static>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TrackedTask<T, R>)>,
+    resource: Option<ResMut<Progress<T>>>,
+    policies: Option<Res<ResetPolicies<T>>>,
+    mut synced: Local<SyncedWork>,
+) {
+    for (entity, mut tracked) in &mut query {
+        if let Some(task) = tracked.task.as_mut() {
+            if let Some(result) = block_on(poll_once(task)) {
+                tracked.task = None;
+
+                match tracked.trigger {
+                    true => commands.trigger_targets(TaskCompleted::<T, R>::new(result), [entity]),
+                    false => tracked.result = Some(result),
+                }
+            }
+        }
+    }
+
+    let mut resource = match resource {
+        Some(v) => v,
+        None => return,
+    };
+
+    record_full_state(policies.and_then(|v| v.resource), &mut resource, &mut synced, |progress| {
+        for (_, tracked) in &query {
+            progress.track(tracked.finished() as u32, 1);
+        }
+    });
+}
+
+/// An asynchronous task that contributes to the [`Progress<T>`] resource.
+///
+/// The task is one unit of work, which is done once it has completed.
+/// Its result can then be taken with [`take_result`](Self::take_result),
+/// or raised as a [`TaskCompleted<T, R>`] event if enabled with [`with_event`](Self::with_event).
+///
+/// Polled by [`TrackedTaskPlugin<T, R>`].
+#[derive(Component)]
+pub struct TrackedTask<T: ?Sized, R> {
+    task: Option<Task<R>>,
+    result: Option<R>,
+    trigger: bool,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized, R> TrackedTask<T, R> {
+    /// Creates a new [`TrackedTask`] from a spawned [`Task`].
+    pub fn new(task: Task<R>) -> Self {
+        Self {
+            task: Some(task),
+            result: None,
+            trigger: false,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Raises a [`TaskCompleted<T, R>`] event with the result when the task completes,
+    /// instead of storing it in the component.
+    pub fn with_event(mut self) -> Self {
+        self.trigger = true;
+        self
+    }
+
+    /// Returns `true` if the task has completed.
+    #[inline]
+    pub fn finished(&self) -> bool {
+        self.task.is_none()
+    }
+
+    /// Takes the result of the task, if it has completed and the result hasn't already been taken.
+    pub fn take_result(&mut self) -> Option<R> {
+        self.result.take()
+    }
+}
+
+/// An observer event raised when a [`TrackedTask<T, R>`] completes, carrying its result.
+///
+/// This is only raised for tasks created with [`TrackedTask::with_event`],
+/// and is targeted at the entity the task was attached to.
+#[derive(Event)]
+pub struct TaskCompleted<T: ?Sized, R> {
+    result: R,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized, R> TaskCompleted<T, R> {
+    fn new(result: R) -> Self {
+        Self {
+            result,
+            _p1: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the result of the task.
+    #[inline]
+    pub fn result(&self) -> &R {
+        &self.result
+    }
+}
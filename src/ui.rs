@@ -0,0 +1,206 @@
+use std::marker::PhantomData;
+use bevy_app::prelude::*;
+use bevy_color::Color;
+use bevy_ecs::{prelude::*, schedule::{ScheduleLabel, InternedScheduleLabel}};
+use bevy_hierarchy::prelude::*;
+use bevy_ui::prelude::*;
+use crate::{Progress, ProgressSystems};
+
+/// Drives [`ProgressBar<T>`] widgets from [`Progress<T>`] trackers.
+pub struct ProgressBarPlugin<T: ?Sized> {
+    /// The schedule in which progress bars are updated.
+    /// This reads trackers, so it must run between the check and the reset. See [`ProgressSystems`].
+    /// It must also be a schedule that UI layout runs in, like [`PostUpdate`].
+    pub schedule: InternedScheduleLabel,
+
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressBarPlugin<T> {
+    fn default() -> Self {
+        Self {
+            schedule: PostUpdate.intern(),
+            _p1: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Plugin for ProgressBarPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(self.schedule, (
+            progress_bar_spawn_system::<T>,
+            progress_bar_update_system::<T>,
+        ).chain()
+            .after(ProgressSystems::Check)
+            .before(bevy_ui::UiSystem::Layout));
+    }
+}
+
+fn progress_bar_spawn_system<T: ?Sized + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ProgressBar<T>), Added<ProgressBar<T>>>,
+    radii: Query<&BorderRadius>,
+) {
+    for (entity, mut bar) in &mut query {
+        let radius = radii.get(entity).copied().unwrap_or_default();
+
+        let fill = commands.spawn(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            background_color: bar.fill_color.into(),
+            border_radius: radius,
+            ..Default::default()
+        }).set_parent(entity).id();
+
+        bar.fill = Some(fill);
+    }
+}
+
+fn progress_bar_update_system<T: ?Sized + Send + Sync + 'static>(
+    resource: Option<Res<Progress<T>>>,
+    trackers: Query<&Progress<T>>,
+    bars: Query<&ProgressBar<T>>,
+    mut fills: Query<(&mut Style, &mut BackgroundColor)>,
+) {
+    for bar in &bars {
+        let fract = match bar.source {
+            Some(entity) => trackers.get(entity).ok().map(Progress::fract),
+            None => resource.as_ref().map(|v| v.fract()),
+        };
+
+        let fract = fract.unwrap_or(0.0).clamp(0.0, 1.0);
+
+        let (mut style, mut color) = match bar.fill.and_then(|v| fills.get_mut(v).ok()) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        let length = Val::Percent(fract * 100.0);
+        let full = Val::Percent(100.0);
+        let zero = Val::Px(0.0);
+
+        let (width, height, left, right, top, bottom) = match bar.orientation {
+            BarOrientation::LeftToRight => (length, full, zero, Val::Auto, zero, Val::Auto),
+            BarOrientation::RightToLeft => (length, full, Val::Auto, zero, zero, Val::Auto),
+            BarOrientation::TopToBottom => (full, length, zero, Val::Auto, zero, Val::Auto),
+            BarOrientation::BottomToTop => (full, length, zero, Val::Auto, Val::Auto, zero),
+        };
+
+        style.width = width;
+        style.height = height;
+        style.left = left;
+        style.right = right;
+        style.top = top;
+        style.bottom = bottom;
+
+        color.0 = bar.fill_color;
+    }
+}
+
+/// The direction in which a [`ProgressBar`] fills.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BarOrientation {
+    /// Fills from the left edge to the right edge.
+    #[default]
+    LeftToRight,
+
+    /// Fills from the right edge to the left edge.
+    RightToLeft,
+
+    /// Fills from the top edge to the bottom edge.
+    TopToBottom,
+
+    /// Fills from the bottom edge to the top edge.
+    BottomToTop,
+}
+
+/// A progress bar widget, showing the fraction of a [`Progress<T>`] tracker.
+///
+/// The entity with this component is the background of the bar.
+/// A fill node is spawned as its child, and resized every tick by [`ProgressBarPlugin<T>`].
+#[derive(Component)]
+pub struct ProgressBar<T: ?Sized> {
+    /// The color of the fill node.
+    pub fill_color: Color,
+
+    /// The direction in which the bar fills.
+    pub orientation: BarOrientation,
+
+    /// The entity whose [`Progress<T>`] is shown, or `None` to show the resource.
+    pub source: Option<Entity>,
+
+    fill: Option<Entity>,
+    _p1: PhantomData<T>,
+}
+
+impl<T: ?Sized> Default for ProgressBar<T> {
+    fn default() -> Self {
+        Self {
+            fill_color: Color::WHITE,
+            orientation: BarOrientation::default(),
+            source: None,
+            fill: None,
+            _p1: PhantomData,
+        }
+    }
+}
+
+/// A bundle for spawning a [`ProgressBar<T>`] widget.
+#[derive(Bundle)]
+pub struct ProgressBarBundle<T: ?Sized + Send + Sync + 'static> {
+    /// The background node of the bar.
+    pub node: NodeBundle,
+
+    /// The progress bar.
+    pub bar: ProgressBar<T>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> ProgressBarBundle<T> {
+    /// Creates a new progress bar showing the [`Progress<T>`] resource.
+    pub fn new(width: Val, height: Val) -> Self {
+        Self {
+            node: NodeBundle {
+                style: Style {
+                    width,
+                    height,
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.into(),
+                ..Default::default()
+            },
+            bar: ProgressBar::default(),
+        }
+    }
+
+    /// Shows the [`Progress<T>`] component of `entity`, instead of the resource.
+    pub fn with_source(mut self, entity: Entity) -> Self {
+        self.bar.source = Some(entity);
+        self
+    }
+
+    /// Sets the color of the background node.
+    pub fn with_background_color(mut self, color: Color) -> Self {
+        self.node.background_color = color.into();
+        self
+    }
+
+    /// Sets the color of the fill node.
+    pub fn with_fill_color(mut self, color: Color) -> Self {
+        self.bar.fill_color = color;
+        self
+    }
+
+    /// Sets the corner radius of both the background and fill nodes.
+    pub fn with_corner_radius(mut self, radius: Val) -> Self {
+        self.node.border_radius = BorderRadius::all(radius);
+        self
+    }
+
+    /// Sets the direction in which the bar fills.
+    pub fn with_orientation(mut self, orientation: BarOrientation) -> Self {
+        self.bar.orientation = orientation;
+        self
+    }
+}
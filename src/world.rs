@@ -0,0 +1,42 @@
+use bevy_ecs::prelude::*;
+use crate::Progress;
+
+/// Extension trait for accessing progress trackers directly through the [`World`].
+///
+/// This is useful in exclusive systems, and in code that builds worlds by hand.
+/// All methods return `None` if the tracker doesn't exist, rather than panicking.
+pub trait WorldProgressExt {
+    /// Returns the [`Progress<T>`] resource, if it exists.
+    fn progress<T: ?Sized + Send + Sync + 'static>(&self) -> Option<&Progress<T>>;
+
+    /// Returns the [`Progress<T>`] resource mutably, if it exists.
+    fn progress_mut<T: ?Sized + Send + Sync + 'static>(&mut self) -> Option<Mut<'_, Progress<T>>>;
+
+    /// Returns the [`Progress<T>`] component of `entity`, if the entity exists and has one.
+    fn entity_progress<T: ?Sized + Send + Sync + 'static>(&self, entity: Entity) -> Option<&Progress<T>>;
+
+    /// Returns the [`Progress<T>`] component of `entity` mutably, if the entity exists and has one.
+    fn entity_progress_mut<T: ?Sized + Send + Sync + 'static>(&mut self, entity: Entity) -> Option<Mut<'_, Progress<T>>>;
+}
+
+impl WorldProgressExt for World {
+    #[inline]
+    fn progress<T: ?Sized + Send + Sync + 'static>(&self) -> Option<&Progress<T>> {
+        self.get_resource::<Progress<T>>()
+    }
+
+    #[inline]
+    fn progress_mut<T: ?Sized + Send + Sync + 'static>(&mut self) -> Option<Mut<'_, Progress<T>>> {
+        self.get_resource_mut::<Progress<T>>()
+    }
+
+    #[inline]
+    fn entity_progress<T: ?Sized + Send + Sync + 'static>(&self, entity: Entity) -> Option<&Progress<T>> {
+        self.get::<Progress<T>>(entity)
+    }
+
+    #[inline]
+    fn entity_progress_mut<T: ?Sized + Send + Sync + 'static>(&mut self, entity: Entity) -> Option<Mut<'_, Progress<T>>> {
+        self.get_mut::<Progress<T>>(entity)
+    }
+}
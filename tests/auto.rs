@@ -0,0 +1,23 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[derive(Component)]
+struct Job;
+
+#[test]
+fn auto_trackers_wait_for_work_before_completing() {
+    let mut app = App::new();
+    app.add_plugins(EntityProgressTrackingPlugin::<Loading>::default());
+    app.add_plugins(AutoProgressPlugin::<Job, Loading>::default());
+
+    let entity = app.world_mut().spawn((Job, DespawnOnDone::<Loading>::new())).id();
+    for _ in 0..3 { app.update() }
+    assert!(app.world().get::<Progress<Loading>>(entity).is_some());
+
+    app.world_mut().get_mut::<Progress<Loading>>(entity).unwrap().track(1, 1);
+    app.update();
+    assert!(app.world().get_entity(entity).is_none());
+}
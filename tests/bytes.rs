@@ -0,0 +1,52 @@
+use bevy_mod_progress::*;
+use bevy_tasks::{block_on, futures_lite::{stream, AsyncReadExt, StreamExt}};
+
+enum Download {}
+
+#[test]
+fn unknown_length_stream_completes_at_end() {
+    let progress = SharedProgress::<Download>::new();
+    let chunks = vec![vec![0u8; 4], vec![0u8; 4], vec![0u8; 2]];
+    let mut stream = progress.track_stream(stream::iter(chunks), None, |chunk| chunk.len() as u64);
+
+    for expected in [4, 8, 10] {
+        assert!(block_on(stream.next()).is_some());
+        let (done, total) = progress.work();
+        assert_eq!(done, expected);
+        assert!(done < total, "tracker completed before the stream ended");
+    }
+
+    assert!(block_on(stream.next()).is_none());
+    assert_eq!(progress.work(), (11, 11));
+
+    // Polling again after the end doesn't record more work
+    assert!(block_on(stream.next()).is_none());
+    assert_eq!(progress.work(), (11, 11));
+}
+
+#[test]
+fn known_length_stream_completes_at_end() {
+    let progress = SharedProgress::<Download>::new();
+    let chunks = vec![vec![0u8; 6], vec![0u8; 4]];
+    let mut stream = progress.track_stream(stream::iter(chunks), Some(10), |chunk| chunk.len() as u64);
+
+    assert_eq!(progress.work(), (0, 11));
+    while block_on(stream.next()).is_some() {}
+    assert_eq!(progress.work(), (11, 11));
+}
+
+#[test]
+fn reader_completes_at_eof() {
+    let progress = SharedProgress::<Download>::new();
+    let mut reader = progress.track_reader(&[1u8, 2, 3, 4, 5][..], None);
+
+    let mut buf = [0; 2];
+    block_on(reader.read_exact(&mut buf)).unwrap();
+    let (done, total) = progress.work();
+    assert_eq!(done, 2);
+    assert!(done < total);
+
+    let mut rest = Vec::new();
+    block_on(reader.read_to_end(&mut rest)).unwrap();
+    assert_eq!(progress.work(), (6, 6));
+}
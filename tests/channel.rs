@@ -0,0 +1,24 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn received_work_is_recorded_once_without_resets() {
+    let mut plugin = ResourceProgressTrackingPlugin::<Loading>::default();
+    plugin.reset_policy = ResetPolicy::Manual;
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app.add_plugins(ProgressChannelPlugin::<Loading>::default());
+    app.insert_resource(Progress::<Loading>::new());
+
+    let sender = app.world().resource::<ProgressReceiver<Loading>>().sender();
+    sender.track(1, 3);
+    for _ in 0..5 { app.update() }
+    assert_eq!(app.world().resource::<Progress<Loading>>().work(), (1, 3));
+
+    sender.track(2, 0);
+    app.update();
+    assert_eq!(app.world().resource::<Progress<Loading>>().work(), (3, 3));
+}
@@ -0,0 +1,41 @@
+// Each test crate only uses some of these
+#![allow(dead_code)]
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+pub enum Loading {}
+
+/// The number of times the event passed to [`count`] was raised.
+#[derive(Resource, Default)]
+pub struct Count(pub u32);
+
+/// Returns an app tracking the [`Progress<Loading>`] resource with `policy`.
+pub fn app(policy: ResetPolicy) -> App {
+    let mut plugin = ResourceProgressTrackingPlugin::<Loading>::default();
+    plugin.reset_policy = policy;
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app.insert_resource(Progress::<Loading>::new());
+    app
+}
+
+/// Counts each time `E` is raised in the [`Count`] resource.
+pub fn count<E: Event>(app: &mut App) {
+    app.init_resource::<Count>();
+    app.observe(|_: Trigger<E>, mut count: ResMut<Count>| count.0 += 1);
+}
+
+pub fn counted(app: &App) -> u32 {
+    app.world().resource::<Count>().0
+}
+
+pub fn track(app: &mut App, done: u32, total: u32) {
+    app.world_mut().resource_mut::<Progress<Loading>>().track(done, total);
+}
+
+pub fn work(app: &App) -> (u64, u64) {
+    app.world().resource::<Progress<Loading>>().work()
+}
@@ -0,0 +1,23 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn replace_keeps_empty_trackers() {
+    let mut plugin = EntityProgressTrackingPlugin::<Loading>::default();
+    plugin.completed_marker = CompletedMarker::Replace;
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+
+    let entity = app.world_mut().spawn(Progress::<Loading>::new()).id();
+    for _ in 0..3 { app.update() }
+    assert!(app.world().get::<Progress<Loading>>(entity).is_some());
+    assert!(app.world().get::<Completed<Loading>>(entity).is_none());
+
+    app.world_mut().get_mut::<Progress<Loading>>(entity).unwrap().track(1, 1);
+    app.update();
+    assert!(app.world().get::<Progress<Loading>>(entity).is_none());
+    assert!(app.world().get::<Completed<Loading>>(entity).is_some());
+}
@@ -0,0 +1,44 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+mod common;
+use common::*;
+
+#[derive(Resource, Default)]
+struct Ran(Vec<&'static str>);
+
+fn app() -> App {
+    let mut app = common::app(ResetPolicy::Manual);
+    app.init_resource::<Ran>();
+
+    // Conditions read the state from the last check, so they run after it
+    app.add_systems(Last, (
+        (|mut ran: ResMut<Ran>| ran.0.push("done")).run_if(progress_done::<Loading>()),
+        (|mut ran: ResMut<Ran>| ran.0.push("not done")).run_if(progress_not_done::<Loading>()),
+        (|mut ran: ResMut<Ran>| ran.0.push("half")).run_if(progress_at_least::<Loading>(0.5)),
+    ).chain());
+
+    app
+}
+
+fn ran(app: &mut App) -> Vec<&'static str> {
+    std::mem::take(&mut app.world_mut().resource_mut::<Ran>().0)
+}
+
+#[test]
+fn conditions_follow_the_last_check() {
+    let mut app = app();
+
+    track(&mut app, 1, 4);
+    app.update();
+    assert_eq!(ran(&mut app), vec!["not done"]);
+
+    track(&mut app, 1, 0);
+    app.update();
+    assert_eq!(ran(&mut app), vec!["not done", "half"]);
+
+    track(&mut app, 2, 0);
+    app.update();
+    assert_eq!(ran(&mut app), vec!["done", "half"]);
+}
@@ -0,0 +1,18 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn empty_trackers_are_not_despawned() {
+    let mut app = App::new();
+    app.add_plugins(EntityProgressTrackingPlugin::<Loading>::default());
+
+    let entity = app.world_mut().spawn((Progress::<Loading>::new(), DespawnOnDone::<Loading>::new())).id();
+    for _ in 0..3 { app.update() }
+    assert!(app.world().get_entity(entity).is_some());
+
+    app.world_mut().get_mut::<Progress<Loading>>(entity).unwrap().track(1, 1);
+    app.update();
+    assert!(app.world().get_entity(entity).is_none());
+}
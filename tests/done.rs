@@ -0,0 +1,58 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+mod common;
+use common::*;
+
+fn app() -> App {
+    let mut app = common::app(ResetPolicy::Manual);
+    count::<Done<Loading>>(&mut app);
+    app
+}
+
+#[test]
+fn done_is_raised_once_per_completion() {
+    let mut app = app();
+
+    track(&mut app, 1, 1);
+    for _ in 0..5 { app.update() }
+    assert_eq!(counted(&app), 1);
+
+    // New work unlatches the tracker until it completes again
+    track(&mut app, 0, 1);
+    app.update();
+    assert_eq!(counted(&app), 1);
+
+    track(&mut app, 1, 0);
+    for _ in 0..5 { app.update() }
+    assert_eq!(counted(&app), 2);
+}
+
+#[test]
+fn done_is_raised_once_when_work_is_recorded_every_tick() {
+    let mut app = app();
+    app.add_systems(Update, |mut progress: ResMut<Progress<Loading>>| progress.track(0, 0));
+
+    track(&mut app, 1, 1);
+    for _ in 0..5 { app.update() }
+    assert_eq!(counted(&app), 1);
+}
+
+#[test]
+fn resource_done_has_no_entity_and_the_final_work() {
+    #[derive(Resource, Default)]
+    struct Completed(Vec<(Option<Entity>, (u64, u64))>);
+
+    let mut app = app();
+    app.init_resource::<Completed>();
+    app.observe(|trigger: Trigger<Done<Loading>>, mut completed: ResMut<Completed>| {
+        completed.0.push((trigger.event().entity(), trigger.event().work()));
+    });
+
+    track(&mut app, 2, 3);
+    app.update();
+    track(&mut app, 1, 0);
+    app.update();
+    assert_eq!(app.world().resource::<Completed>().0, vec![(None, (3, 3))]);
+}
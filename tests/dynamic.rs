@@ -0,0 +1,73 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+#[derive(Resource, Default)]
+struct Events(Vec<String>);
+
+fn app(policy: ResetPolicy) -> App {
+    let mut app = App::new();
+    app.add_plugins(DynamicProgressPlugin {
+        reset_policy: policy,
+        ..Default::default()
+    });
+
+    app.init_resource::<Events>();
+    app.observe(|trigger: Trigger<DynamicUpdated>, mut events: ResMut<Events>| {
+        events.0.push(format!("updated {} {:?}", trigger.event().key(), trigger.event().work()));
+    });
+    app.observe(|trigger: Trigger<DynamicStalled>, mut events: ResMut<Events>| {
+        events.0.push(format!("stalled {}", trigger.event().key()));
+    });
+    app.observe(|trigger: Trigger<DynamicFailed>, mut events: ResMut<Events>| {
+        events.0.push(format!("failed {} {}", trigger.event().key(), trigger.event().failed()));
+    });
+    app.observe(|trigger: Trigger<DynamicDone>, mut events: ResMut<Events>| {
+        events.0.push(format!("done {}", trigger.event().key()));
+    });
+
+    app
+}
+
+fn events(app: &mut App) -> Vec<String> {
+    std::mem::take(&mut app.world_mut().resource_mut::<Events>().0)
+}
+
+#[test]
+fn trackers_follow_the_reset_policy() {
+    let mut app = app(ResetPolicy::OnDone);
+
+    app.world_mut().resource_mut::<DynamicProgress>().track("mods", 1, 2);
+    app.update();
+    assert_eq!(events(&mut app), ["updated mods (1, 2)"]);
+
+    // Incomplete trackers aren't reset, so work accumulates
+    app.world_mut().resource_mut::<DynamicProgress>().track("mods", 1, 0);
+    app.update();
+    assert_eq!(events(&mut app), ["updated mods (2, 2)", "done mods"]);
+    assert_eq!(app.world().resource::<DynamicProgress>().get("mods").unwrap().work(), (0, 0));
+}
+
+#[test]
+fn stalls_and_failures_are_raised() {
+    let mut app = app(ResetPolicy::Manual);
+
+    let mut tracker = Progress::new().with_stall_threshold(StallThreshold::Frames(2));
+    tracker.track(0, 2);
+    tracker.track_failed(1);
+    app.world_mut().resource_mut::<DynamicProgress>().insert("scripts", tracker);
+
+    for _ in 0..3 { app.update() }
+    assert_eq!(events(&mut app), ["updated scripts (0, 3)", "failed scripts 1", "stalled scripts"]);
+}
+
+#[test]
+fn nothing_is_checked_while_disabled() {
+    let mut app = app(ResetPolicy::EveryTick);
+    app.insert_resource(ProgressTrackingEnabled::<DynamicProgress>::new(false));
+
+    app.world_mut().resource_mut::<DynamicProgress>().track("mods", 1, 1);
+    for _ in 0..3 { app.update() }
+    assert!(events(&mut app).is_empty());
+    assert_eq!(app.world().resource::<DynamicProgress>().get("mods").unwrap().work(), (1, 1));
+}
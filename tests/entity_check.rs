@@ -0,0 +1,109 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[derive(Resource, Default)]
+struct Counts {
+    done: u32,
+    stalled: u32,
+}
+
+fn app(plugin: EntityProgressTrackingPlugin<Loading>) -> App {
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app.init_resource::<Counts>();
+    app.observe(|_: Trigger<Done<Loading>>, mut counts: ResMut<Counts>| counts.done += 1);
+    app.observe(|_: Trigger<Stalled<Loading>>, mut counts: ResMut<Counts>| counts.stalled += 1);
+    app
+}
+
+fn manual_reset() -> EntityProgressTrackingPlugin<Loading> {
+    let mut plugin = EntityProgressTrackingPlugin::default();
+    plugin.reset_policy = ResetPolicy::Manual;
+    plugin
+}
+
+#[test]
+fn done_is_raised_once_while_complete() {
+    let mut app = app(EntityProgressTrackingPlugin::default());
+    app.world_mut().spawn(Progress::<Loading>::new());
+    app.add_systems(Update, |mut query: Query<&mut Progress<Loading>>| {
+        for mut tracker in &mut query { tracker.track(1, 1) }
+    });
+
+    for _ in 0..5 { app.update() }
+    assert_eq!(app.world().resource::<Counts>().done, 1);
+}
+
+#[test]
+fn unchanged_trackers_still_stall() {
+    let mut app = app(manual_reset());
+
+    let mut tracker = Progress::<Loading>::new()
+        .with_stall_threshold(StallThreshold::Frames(3));
+    tracker.track(1, 2);
+    app.world_mut().spawn(tracker);
+
+    // The tracker is never touched again, so it's only checked because it can stall
+    for _ in 0..6 { app.update() }
+    assert_eq!(app.world().resource::<Counts>().stalled, 1);
+    assert_eq!(app.world().resource::<Counts>().done, 0);
+}
+
+#[test]
+fn idle_trackers_are_checked_after_changing() {
+    let mut app = app(manual_reset());
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(1, 2);
+    let entity = app.world_mut().spawn(tracker).id();
+    for _ in 0..3 { app.update() }
+    assert_eq!(app.world().resource::<Counts>().done, 0);
+
+    app.world_mut().get_mut::<Progress<Loading>>(entity).unwrap().track(1, 0);
+    app.update();
+    assert_eq!(app.world().resource::<Counts>().done, 1);
+}
+
+#[test]
+fn sparse_storage_is_checked() {
+    let mut app = app(EntityProgressTrackingPlugin::default()
+        .with_storage(ProgressStorage::SparseSet));
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(1, 1);
+    app.world_mut().spawn(SparseProgress::new(tracker));
+
+    app.update();
+    assert_eq!(app.world().resource::<Counts>().done, 1);
+}
+
+#[derive(Resource, Default)]
+struct Completions(Vec<(Entity, Option<Entity>, (u64, u64))>);
+
+#[test]
+fn trackers_completing_together_keep_their_own_done() {
+    let mut app = app(EntityProgressTrackingPlugin::default());
+    app.init_resource::<Completions>();
+    app.observe(|trigger: Trigger<Done<Loading>>, mut completions: ResMut<Completions>| {
+        completions.0.push((trigger.entity(), trigger.event().entity(), trigger.event().work()));
+    });
+
+    let mut first = Progress::<Loading>::new();
+    first.track(1, 1);
+    let first = app.world_mut().spawn(first).id();
+
+    let mut second = Progress::<Loading>::new();
+    second.track(2, 2);
+    let second = app.world_mut().spawn(second).id();
+
+    app.update();
+    let completions = &mut app.world_mut().resource_mut::<Completions>().0;
+    completions.sort_by_key(|v| v.0);
+
+    let mut expected = vec![(first, Some(first), (1, 1)), (second, Some(second), (2, 2))];
+    expected.sort_by_key(|v| v.0);
+    assert_eq!(*completions, expected);
+}
@@ -0,0 +1,34 @@
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn invalid_amounts_are_ignored() {
+    let mut progress = Progress::<Loading>::new();
+    progress.track(1, 2);
+
+    progress.track_weighted(1, 1, -1.0);
+    progress.track_weighted(1, 1, f32::INFINITY);
+    progress.track_weighted(1, 1, f32::NAN);
+    progress.track_f64(f64::NAN, 1.0);
+    progress.track_f64(1.0, f64::INFINITY);
+    progress.track_f64(-1.0, 0.0);
+
+    assert_eq!(progress.work(), (1, 2));
+    assert_eq!(progress.float_work(), (0.0, 0.0));
+    assert_eq!(progress.fract(), 0.5);
+}
+
+#[test]
+fn weights_scale_fract_but_not_work() {
+    let mut progress = Progress::<Loading>::new();
+    progress.track_weighted(1, 1, 3.0);
+    progress.track(0, 1);
+
+    assert_eq!(progress.work(), (1, 2));
+    assert_eq!(progress.fract(), 0.75);
+
+    progress.untrack_weighted(1, 1, 3.0);
+    assert_eq!(progress.work(), (0, 1));
+    assert_eq!(progress.fract(), 0.0);
+}
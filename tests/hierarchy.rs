@@ -0,0 +1,76 @@
+#![cfg(feature="bevy_hierarchy")]
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[derive(Resource, Default)]
+struct Completed(Vec<(Entity, (u64, u64))>);
+
+#[derive(Component)]
+struct Work(u32);
+
+fn app(policy: ResetPolicy) -> App {
+    let mut plugin = EntityProgressTrackingPlugin::<Loading>::default();
+    plugin.reset_policy = policy;
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app.add_plugins(HierarchicalProgressPlugin::<Loading>::default());
+    app.init_resource::<Completed>();
+    app.observe(|trigger: Trigger<Done<Loading>>, mut completed: ResMut<Completed>| {
+        let event = trigger.event();
+        completed.0.push((event.entity().unwrap(), event.work()));
+    });
+
+    // Each tracker records its full state every tick
+    app.add_systems(Update, |mut query: Query<(&mut Progress<Loading>, &Work)>| {
+        for (mut tracker, work) in &mut query { tracker.track(work.0, 2) }
+    });
+
+    app
+}
+
+#[test]
+fn parents_complete_after_children() {
+    let mut app = app(ResetPolicy::EveryTick);
+
+    let world = app.world_mut();
+    let slow = world.spawn((Progress::<Loading>::new(), Work(0))).id();
+    let fast = world.spawn((Progress::<Loading>::new(), Work(2))).id();
+    let parent = world.spawn((Progress::<Loading>::new(), Work(2))).push_children(&[slow, fast]).id();
+
+    // The aggregate is recomputed every tick, so it doesn't grow while waiting
+    for _ in 0..5 { app.update() }
+    assert_eq!(app.world().resource::<Completed>().0, vec![(fast, (2, 2))]);
+
+    app.world_mut().get_mut::<Work>(slow).unwrap().0 = 2;
+    app.update();
+
+    let completed = &app.world().resource::<Completed>().0;
+    assert!(completed.contains(&(slow, (2, 2))));
+    assert!(completed.contains(&(parent, (6, 6))));
+}
+
+#[test]
+fn other_reset_policies_keep_children_counted_once() {
+    let mut app = app(ResetPolicy::Manual);
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(1, 2);
+
+    let world = app.world_mut();
+    let child = world.spawn(tracker.clone()).id();
+    let parent = world.spawn(tracker).add_child(child).id();
+
+    // Trackers keep their work, so only changes in the child's work are added to the parent
+    for _ in 0..5 { app.update() }
+    assert_eq!(app.world().get::<Progress<Loading>>(parent).unwrap().work(), (2, 4));
+
+    app.world_mut().get_mut::<Progress<Loading>>(child).unwrap().track(1, 0);
+    for _ in 0..5 { app.update() }
+    assert_eq!(app.world().get::<Progress<Loading>>(parent).unwrap().work(), (3, 4));
+}
@@ -0,0 +1,71 @@
+#![cfg(feature="http")]
+
+use std::{io::{Read, Write}, net::{SocketAddr, TcpStream}};
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+enum Unused {}
+
+fn app(port: u16) -> (App, SocketAddr) {
+    let address = SocketAddr::from(([127, 0, 0, 1], port));
+    let mut app = App::new();
+    app.add_plugins(ResourceProgressTrackingPlugin::<Loading>::default());
+    app.add_plugins(ResourceProgressTrackingPlugin::<Unused>::default());
+    app.add_plugins(ProgressStatusPlugin::default().with_address(address));
+    app.insert_resource(Progress::<Loading>::new());
+    app.insert_resource(Progress::<Unused>::new());
+    (app, address)
+}
+
+fn get(address: SocketAddr) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn empty_trackers_are_not_ready() {
+    let (mut app, address) = app(39101);
+
+    for _ in 0..3 { app.update() }
+    let response = get(address);
+    assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+    assert!(response.contains("\"done\":false"), "{response}");
+}
+
+#[test]
+fn ready_once_work_completes() {
+    let (mut app, address) = app(39102);
+
+    app.world_mut().resource_mut::<Progress<Loading>>().track(1, 2);
+    app.update();
+    assert!(get(address).starts_with("HTTP/1.1 503"));
+
+    app.world_mut().resource_mut::<Progress<Loading>>().track(2, 2);
+    app.update();
+    assert!(get(address).starts_with("HTTP/1.1 200"));
+
+    // Trackers are reset every tick, but the app stays ready
+    for _ in 0..3 { app.update() }
+    let response = get(address);
+    assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+    assert!(response.contains("\"done\":true"), "{response}");
+
+    // New work makes the app unready again
+    app.world_mut().resource_mut::<Progress<Loading>>().track(0, 1);
+    app.update();
+    assert!(get(address).starts_with("HTTP/1.1 503"));
+}
+
+#[test]
+fn control_characters_are_escaped() {
+    let (mut app, address) = app(39103);
+    app.insert_resource(ProgressStages::<Loading>::new().with_stage("line\n\ttab\u{1}", 1.0));
+
+    app.update();
+    let response = get(address);
+    assert!(response.contains("\"stage\":\"line\\u000a\\u0009tab\\u0001\""), "{response}");
+}
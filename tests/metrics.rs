@@ -0,0 +1,52 @@
+#![cfg(feature="prometheus")]
+
+use std::{io::{Read, Write}, net::{SocketAddr, TcpStream}};
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+fn get(address: SocketAddr) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn completions(response: &str) -> &str {
+    response.lines()
+        .find(|line| line.starts_with("bevy_progress_completions_total{"))
+        .and_then(|line| line.rsplit(' ').next())
+        .unwrap()
+}
+
+#[test]
+fn only_completed_work_is_counted() {
+    let address = SocketAddr::from(([127, 0, 0, 1], 39201));
+    let mut app = App::new();
+    app.add_plugins(ResourceProgressTrackingPlugin::<Loading>::default());
+    app.add_plugins(ProgressMetricsPlugin::default().with_address(address));
+    app.insert_resource(Progress::<Loading>::new());
+
+    // The tracker is empty, so nothing has completed
+    for _ in 0..3 { app.update() }
+    assert_eq!(completions(&get(address)), "0");
+
+    app.world_mut().resource_mut::<Progress<Loading>>().track(1, 2);
+    app.update();
+    app.world_mut().resource_mut::<Progress<Loading>>().track(2, 2);
+    app.update();
+
+    let response = get(address);
+    assert_eq!(completions(&response), "1");
+    assert!(response.contains("bevy_progress_total{type=\""), "{response}");
+
+    // Going idle after the reset isn't another completion
+    for _ in 0..3 { app.update() }
+    assert_eq!(completions(&get(address)), "1");
+
+    app.world_mut().resource_mut::<Progress<Loading>>().track(1, 1);
+    app.update();
+    assert_eq!(completions(&get(address)), "2");
+}
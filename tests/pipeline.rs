@@ -0,0 +1,39 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+enum Assets {}
+enum Level {}
+
+#[derive(Resource, Default)]
+struct Completed(bool);
+
+#[test]
+fn stages_advance_once_their_work_completes() {
+    let mut app = App::new();
+    app.add_plugins(ResourceProgressTrackingPlugin::<Assets>::default());
+    app.add_plugins(ResourceProgressTrackingPlugin::<Level>::default());
+    app.add_plugins(pipeline::<Assets>().then::<Level>());
+    app.init_resource::<Completed>();
+    app.observe(|_: Trigger<PipelineComplete>, mut completed: ResMut<Completed>| completed.0 = true);
+
+    // Empty stages haven't completed anything
+    for _ in 0..3 { app.update() }
+    assert_eq!(app.world().resource::<ProgressPipelineState>().stage(), (0, 2));
+
+    app.world_mut().resource_mut::<Progress<Assets>>().track(1, 2);
+    app.update();
+    assert_eq!(app.world().resource::<ProgressPipelineState>().stage(), (0, 2));
+
+    app.world_mut().resource_mut::<Progress<Assets>>().track(2, 2);
+    app.update();
+    assert_eq!(app.world().resource::<ProgressPipelineState>().stage(), (1, 2));
+
+    app.update();
+    assert_eq!(app.world().resource::<ProgressPipelineState>().stage(), (1, 2));
+
+    app.world_mut().resource_mut::<Progress<Level>>().track(1, 1);
+    app.update();
+    assert!(app.world().resource::<ProgressPipelineState>().is_done());
+    assert!(app.world().resource::<Completed>().0);
+}
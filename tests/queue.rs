@@ -0,0 +1,40 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn jobs_are_recorded_once_without_resets() {
+    let mut plugin = ResourceProgressTrackingPlugin::<Loading>::default();
+    plugin.reset_policy = ResetPolicy::Manual;
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app.add_plugins(WorkQueuePlugin::<Loading>::default());
+    app.insert_resource(Progress::<Loading>::new());
+
+    let mut queue = app.world_mut().resource_mut::<WorkQueue<Loading>>();
+    for _ in 0..3 { queue.push(|_| {}) }
+
+    for _ in 0..5 { app.update() }
+    assert_eq!(app.world().resource::<Progress<Loading>>().work(), (3, 3));
+
+    // The queue starts counting over, but the tracker keeps what it recorded
+    app.world_mut().resource_mut::<WorkQueue<Loading>>().push(|_| {});
+    app.update();
+    assert_eq!(app.world().resource::<Progress<Loading>>().work(), (4, 4));
+}
+
+#[test]
+fn jobs_wait_while_disabled() {
+    let mut app = App::new();
+    app.add_plugins(ResourceProgressTrackingPlugin::<Loading>::default());
+    app.add_plugins(WorkQueuePlugin::<Loading>::default());
+    app.insert_resource(Progress::<Loading>::new());
+    app.insert_resource(ProgressTrackingEnabled::<Loading>::new(false));
+
+    app.world_mut().resource_mut::<WorkQueue<Loading>>().push(|_| {});
+    for _ in 0..3 { app.update() }
+    assert_eq!(app.world().resource::<WorkQueue<Loading>>().work(), (0, 1));
+    assert_eq!(app.world().resource::<Progress<Loading>>().work(), (0, 0));
+}
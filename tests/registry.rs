@@ -0,0 +1,33 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn entities_include_sparse_trackers() {
+    let mut app = App::new();
+    app.add_plugins(EntityProgressTrackingPlugin::<Loading>::default()
+        .with_storage(ProgressStorage::SparseSet));
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(1, 2);
+    let sparse = app.world_mut().spawn(SparseProgress::new(tracker)).id();
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(2, 2);
+    let table = app.world_mut().spawn(tracker).id();
+
+    let registry = app.world().resource::<ProgressRegistry>();
+    let mut entities = registry.get::<Loading>().unwrap().entities(app.world());
+    entities.sort_by_key(|(entity, _)| *entity);
+
+    let mut expected = vec![(sparse, (1, 2)), (table, (2, 2))];
+    expected.sort_by_key(|(entity, _)| *entity);
+    assert_eq!(entities.iter().map(|(entity, info)| (*entity, info.work())).collect::<Vec<_>>(), expected);
+
+    // Trackers on entities spawned later are seen too
+    let later = app.world_mut().spawn(Progress::<Loading>::new()).id();
+    let registry = app.world().resource::<ProgressRegistry>();
+    let entities = registry.get::<Loading>().unwrap().entities(app.world());
+    assert!(entities.iter().any(|(entity, _)| *entity == later));
+}
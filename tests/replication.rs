@@ -0,0 +1,42 @@
+#![cfg(feature="replication")]
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[test]
+fn local_updates_are_not_applied_as_remote() {
+    let mut app = App::new();
+    app.add_plugins(ResourceProgressTrackingPlugin::<Loading>::default());
+    app.add_plugins(ProgressReplicationPlugin::<Loading>::default());
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(1, 2);
+    app.insert_resource(tracker);
+
+    for _ in 0..3 { app.update() }
+    assert!(!app.world().resource::<Events<ProgressUpdate<Loading>>>().is_empty());
+    assert_eq!(app.world().resource::<PeerProgress<Loading>>().iter().count(), 0);
+}
+
+#[test]
+fn resources_of_peers_are_kept_apart() {
+    let mut app = App::new();
+    app.add_plugins(ProgressReplicationPlugin::<Loading>::default().receive_only());
+
+    let mut first = Progress::<Loading>::new();
+    first.track(1, 2);
+    let mut second = Progress::<Loading>::new();
+    second.track(3, 4);
+
+    let mut events = app.world_mut().resource_mut::<Events<ReceivedProgressUpdate<Loading>>>();
+    events.send(ReceivedProgressUpdate::new(1, ProgressUpdate::new(&first, None)));
+    events.send(ReceivedProgressUpdate::new(2, ProgressUpdate::new(&second, None)));
+    app.update();
+
+    let peers = app.world().resource::<PeerProgress<Loading>>();
+    assert_eq!(peers.get(1).map(RemoteProgress::work), Some((1, 2)));
+    assert_eq!(peers.get(2).map(RemoteProgress::work), Some((3, 4)));
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature="replicon", feature="bevy_time"))]
+
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+use bevy_replicon::{prelude::*, test_app::ServerTestAppExt};
+use bevy_time::TimePlugin;
+
+enum Loading {}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        TimePlugin,
+        RepliconPlugins.set(ServerPlugin {
+            tick_policy: TickPolicy::EveryFrame,
+            ..Default::default()
+        }),
+    ));
+
+    let mut tracking = ResourceProgressTrackingPlugin::<Loading>::default();
+    tracking.reset_policy = ResetPolicy::Manual;
+    app.add_plugins(tracking);
+    app.add_plugins(RepliconProgressPlugin::<Loading>::default());
+    app.insert_resource(Progress::<Loading>::new());
+    app
+}
+
+fn client_id(app: &App) -> u64 {
+    app.world().resource::<RepliconClient>().id().unwrap().get()
+}
+
+fn peers(app: &App) -> Vec<(u64, (u64, u64))> {
+    let mut peers: Vec<_> = app.world().resource::<PeerProgress<Loading>>().iter()
+        .map(|(peer, remote)| (peer, remote.work()))
+        .collect();
+
+    peers.sort();
+    peers
+}
+
+#[test]
+fn updates_reach_every_other_peer() {
+    let mut server = app();
+    let mut first = app();
+    let mut second = app();
+    server.connect_client(&mut first);
+    server.connect_client(&mut second);
+
+    first.world_mut().resource_mut::<Progress<Loading>>().track(1, 2);
+    second.world_mut().resource_mut::<Progress<Loading>>().track(3, 4);
+    server.world_mut().resource_mut::<Progress<Loading>>().track(5, 6);
+
+    for _ in 0..3 {
+        first.update();
+        second.update();
+        server.exchange_with_client(&mut first);
+        server.exchange_with_client(&mut second);
+        server.update();
+        server.exchange_with_client(&mut first);
+        server.exchange_with_client(&mut second);
+    }
+
+    first.update();
+    second.update();
+
+    let (first_id, second_id) = (client_id(&first), client_id(&second));
+
+    // Each peer sees every other peer's resource separately, but never its own
+    assert_eq!(peers(&server), [(first_id, (1, 2)), (second_id, (3, 4))]);
+    assert_eq!(peers(&first), [(0, (5, 6)), (second_id, (3, 4))]);
+    assert_eq!(peers(&second), [(0, (5, 6)), (first_id, (1, 2))]);
+}
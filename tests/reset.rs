@@ -0,0 +1,68 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+mod common;
+use common::*;
+
+fn app(policy: ResetPolicy) -> App {
+    let mut app = common::app(policy);
+    count::<Done<Loading>>(&mut app);
+    app
+}
+
+#[test]
+fn every_tick_resets_incomplete_work() {
+    let mut app = app(ResetPolicy::EveryTick);
+
+    track(&mut app, 1, 2);
+    app.update();
+    assert_eq!(work(&app), (0, 0));
+    assert_eq!(counted(&app), 0);
+}
+
+#[test]
+fn on_done_accumulates_until_complete() {
+    let mut app = app(ResetPolicy::OnDone);
+
+    track(&mut app, 1, 2);
+    app.update();
+    assert_eq!(work(&app), (1, 2));
+
+    track(&mut app, 1, 0);
+    app.update();
+    assert_eq!(work(&app), (0, 0));
+    assert_eq!(counted(&app), 1);
+}
+
+#[test]
+fn manual_keeps_completed_work() {
+    let mut app = app(ResetPolicy::Manual);
+
+    track(&mut app, 2, 2);
+    for _ in 0..3 { app.update() }
+    assert_eq!(work(&app), (2, 2));
+    assert_eq!(counted(&app), 1);
+
+    // Requested resets are applied in the reset systems
+    app.world_mut().trigger(ResetProgress::<Loading>::new());
+    assert_eq!(work(&app), (2, 2));
+    app.update();
+    assert_eq!(work(&app), (0, 0));
+}
+
+#[test]
+fn full_state_sources_record_work_once() {
+    let mut app = app(ResetPolicy::Manual);
+    app.add_plugins(ProgressTaskPlugin::<Loading>::default());
+
+    let task = app.world_mut().spawn(ProgressTask::<Loading>::new("task")).id();
+    app.world_mut().get_mut::<ProgressTask<Loading>>(task).unwrap().track(1, 4);
+    for _ in 0..3 { app.update() }
+    assert_eq!(work(&app), (1, 4));
+
+    // Only the change in the task's work is recorded
+    app.world_mut().get_mut::<ProgressTask<Loading>>(task).unwrap().track(3, 0);
+    app.update();
+    assert_eq!(work(&app), (4, 4));
+    assert_eq!(counted(&app), 1);
+}
@@ -0,0 +1,72 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+use bevy_utils::Duration;
+
+enum Loading {}
+
+#[derive(Resource, Default)]
+struct Counts {
+    attempts: u32,
+    exhausted: u32,
+}
+
+fn app(policy: RetryPolicy<Loading>) -> App {
+    let mut plugin = ResourceProgressTrackingPlugin::<Loading>::default();
+    plugin.reset_policy = ResetPolicy::Manual;
+
+    let mut app = App::new();
+    app.add_plugins((plugin, RetryPlugin::<Loading>::default()));
+    app.init_resource::<Counts>();
+    app.insert_resource(policy);
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track_failed(1);
+    app.insert_resource(tracker);
+
+    // Every attempt fails again
+    app.observe(|_: Trigger<RetryAttempt<Loading>>, mut counts: ResMut<Counts>, mut tracker: ResMut<Progress<Loading>>| {
+        counts.attempts += 1;
+        tracker.track_failed(1);
+    });
+
+    app.observe(|_: Trigger<RetryExhausted<Loading>>, mut counts: ResMut<Counts>| counts.exhausted += 1);
+    app
+}
+
+#[test]
+fn retries_until_exhausted() {
+    let mut app = app(RetryPolicy::new(3));
+    for _ in 0..10 { app.update() }
+
+    let counts = app.world().resource::<Counts>();
+    assert_eq!(counts.attempts, 3);
+    assert_eq!(counts.exhausted, 1);
+}
+
+#[test]
+fn backoff_delays_retries() {
+    let mut app = app(RetryPolicy::new(3).with_backoff(Duration::from_secs(3600)));
+    for _ in 0..5 { app.update() }
+
+    let counts = app.world().resource::<Counts>();
+    assert_eq!(counts.attempts, 0);
+    assert_eq!(app.world().resource::<RetryPolicy<Loading>>().attempts(), 1);
+}
+
+#[test]
+fn extreme_multipliers_dont_panic() {
+    for multiplier in [f32::INFINITY, f32::NAN, -2.0, 1e30] {
+        let policy = RetryPolicy::new(100)
+            .with_backoff(Duration::from_nanos(1))
+            .with_backoff_multiplier(multiplier)
+            .with_max_backoff(Duration::ZERO);
+
+        let mut app = app(policy);
+        for _ in 0..150 { app.update() }
+
+        let counts = app.world().resource::<Counts>();
+        assert_eq!(counts.attempts, 100, "multiplier {multiplier}");
+        assert_eq!(counts.exhausted, 1, "multiplier {multiplier}");
+    }
+}
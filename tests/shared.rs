@@ -0,0 +1,64 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_mod_progress::*;
+
+enum Loading {}
+
+#[derive(Resource, Default)]
+struct Completed(Vec<(u64, u64)>);
+
+fn app(policy: ResetPolicy) -> App {
+    let mut plugin = ResourceProgressTrackingPlugin::<Loading>::default();
+    plugin.reset_policy = policy;
+
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app.add_plugins(SharedProgressPlugin::<Loading>::default());
+    app.insert_resource(Progress::<Loading>::new());
+    app
+}
+
+fn work(app: &App) -> (u64, u64) {
+    app.world().resource::<Progress<Loading>>().work()
+}
+
+#[test]
+fn work_is_recorded_once_without_resets() {
+    let mut app = app(ResetPolicy::Manual);
+    let shared = app.world().resource::<SharedProgress<Loading>>().clone();
+
+    shared.track(1, 3);
+    for _ in 0..5 { app.update() }
+    assert_eq!(work(&app), (1, 3));
+
+    shared.track(1, 0);
+    app.update();
+    assert_eq!(work(&app), (2, 3));
+
+    // Clearing the handle starts over, without removing what was already recorded
+    shared.clear();
+    shared.track(1, 1);
+    app.update();
+    assert_eq!(work(&app), (3, 4));
+}
+
+#[test]
+fn nothing_is_recorded_while_disabled() {
+    let mut app = app(ResetPolicy::EveryTick);
+    let shared = app.world().resource::<SharedProgress<Loading>>().clone();
+    app.insert_resource(ProgressTrackingEnabled::<Loading>::new(false));
+
+    // The tracker isn't reset while disabled, so recording the full state every tick would count it again
+    shared.track(2, 2);
+    for _ in 0..3 { app.update() }
+    assert_eq!(work(&app), (0, 0));
+
+    app.insert_resource(ProgressTrackingEnabled::<Loading>::new(true));
+    app.init_resource::<Completed>();
+    app.observe(|trigger: Trigger<Done<Loading>>, mut completed: ResMut<Completed>| {
+        completed.0.push(trigger.event().work());
+    });
+
+    app.update();
+    assert_eq!(app.world().resource::<Completed>().0, vec![(2, 2)]);
+}
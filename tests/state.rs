@@ -0,0 +1,31 @@
+#![cfg(feature="bevy_state")]
+
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+use bevy_state::prelude::*;
+
+enum Loading {}
+
+#[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+enum GameState {
+    #[default]
+    Loading,
+    Playing,
+}
+
+#[test]
+fn transitions_once_work_completes() {
+    let mut app = App::new();
+    app.init_resource::<NextState<GameState>>();
+    app.add_plugins(ResourceProgressTrackingPlugin::<Loading>::default());
+    app.add_plugins(TransitionOnDone::<Loading, _>::new(GameState::Playing));
+    app.insert_resource(Progress::<Loading>::new());
+
+    // The tracker is empty, so it's done, but hasn't completed anything
+    for _ in 0..3 { app.update() }
+    assert!(matches!(app.world().resource::<NextState<GameState>>(), NextState::Unchanged));
+
+    app.world_mut().resource_mut::<Progress<Loading>>().track(1, 1);
+    app.update();
+    assert!(matches!(app.world().resource::<NextState<GameState>>(), NextState::Pending(GameState::Playing)));
+}
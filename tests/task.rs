@@ -0,0 +1,37 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+
+mod common;
+use common::*;
+
+fn app() -> App {
+    let mut app = common::app(ResetPolicy::EveryTick);
+    app.add_plugins(ProgressTaskPlugin::<Loading>::default());
+    app.add_plugins(ProgressStagesPlugin::<Loading>::default());
+    app.insert_resource(ProgressStages::<Loading>::new().with_stage("stage", 1.0));
+    app
+}
+
+#[test]
+fn nothing_is_recorded_while_disabled() {
+    let mut app = app();
+    app.insert_resource(ProgressTrackingEnabled::<Loading>::new(false));
+
+    let mut task = ProgressTask::<Loading>::new("task");
+    task.track(1, 2);
+    app.world_mut().spawn(task);
+
+    // The tracker isn't reset while disabled, so recording every tick would count work again
+    for _ in 0..3 { app.update() }
+    assert_eq!(work(&app), (0, 0));
+    assert_eq!(app.world().resource::<Progress<Loading>>().float_work(), (0.0, 0.0));
+}
+
+#[test]
+fn empty_tasks_have_no_progress() {
+    let mut task = ProgressTask::<Loading>::new("task");
+    assert_eq!(task.fract(), Progress::<Loading>::new().fract());
+
+    task.track(1, 4);
+    assert_eq!(task.fract(), 0.25);
+}
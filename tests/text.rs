@@ -0,0 +1,21 @@
+#![cfg(feature="bevy_text")]
+
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+use bevy_text::Text;
+
+enum Loading {}
+
+#[test]
+fn percentages_round_down() {
+    let mut app = App::new();
+    app.add_plugins(ProgressTextPlugin::<Loading>::default());
+
+    let mut tracker = Progress::<Loading>::new();
+    tracker.track(996, 1000);
+    app.insert_resource(tracker);
+
+    let text = app.world_mut().spawn((ProgressText::<Loading>::default(), Text::from_section("", Default::default()))).id();
+    app.update();
+    assert_eq!(app.world().get::<Text>(text).unwrap().sections[0].value, "996/1000 (99%)");
+}
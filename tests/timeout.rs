@@ -0,0 +1,45 @@
+use bevy_app::prelude::*;
+use bevy_mod_progress::*;
+use bevy_utils::Duration;
+
+mod common;
+use common::*;
+
+fn app() -> App {
+    let mut app = common::app(ResetPolicy::Manual);
+    app.add_plugins(ProgressTimeoutPlugin::<Loading>::default());
+    count::<TimedOut<Loading>>(&mut app);
+    app
+}
+
+#[test]
+fn times_out_once_per_run() {
+    let mut app = app();
+    app.insert_resource(ProgressTimeout::<Loading>::new(Duration::ZERO));
+
+    track(&mut app, 1, 2);
+    for _ in 0..3 { app.update() }
+    assert_eq!(counted(&app), 1);
+    assert!(app.world().resource::<ProgressTimeout<Loading>>().timed_out());
+
+    // Completing ends the run, and new work starts another
+    track(&mut app, 1, 0);
+    app.update();
+    assert!(!app.world().resource::<ProgressTimeout<Loading>>().timed_out());
+
+    track(&mut app, 0, 1);
+    for _ in 0..3 { app.update() }
+    assert_eq!(counted(&app), 2);
+}
+
+#[test]
+fn completing_in_time_does_not_time_out() {
+    let mut app = app();
+    app.insert_resource(ProgressTimeout::<Loading>::new(Duration::from_secs(3600)));
+
+    track(&mut app, 1, 2);
+    app.update();
+    track(&mut app, 1, 0);
+    for _ in 0..3 { app.update() }
+    assert_eq!(counted(&app), 0);
+}